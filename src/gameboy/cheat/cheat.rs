@@ -0,0 +1,122 @@
+use crate::gameboy::cheat::cheat_error::CheatError;
+
+/// A single decoded cheat code
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum CheatCode {
+    /// Replaces the value the game reads from ROM. With a compare value, only reads that would return that value are replaced, which
+    /// keeps the patch from applying to other ROM banks mapped to the same address.
+    GameGenie {
+        address: u16,
+        value: u8,
+        compare: Option<u8>,
+    },
+    /// Writes the value to RAM once per frame. The bank byte is kept as written in the code, the write always goes to the bank that is
+    /// currently mapped.
+    GameShark { bank: u8, address: u16, value: u8 },
+}
+
+impl CheatCode {
+    /// Parses a Game Genie code (ABC-DEF or ABC-DEF-GHI) or a GameShark code (01VVAAAA).
+    pub fn parse(code: &str) -> Result<Self, CheatError> {
+        let code = code.trim();
+        let invalid = || CheatError::InvalidCode(code.to_string());
+        let digits: Vec<u8> = code
+            .chars()
+            .filter(|&c| c != '-')
+            .map(|c| c.to_digit(16).map(|digit| digit as u8))
+            .collect::<Option<_>>()
+            .ok_or_else(invalid)?;
+
+        if code.contains('-') {
+            return Self::parse_game_genie(&digits).ok_or_else(invalid);
+        }
+        Self::parse_game_shark(&digits).ok_or_else(invalid)
+    }
+
+    fn parse_game_genie(digits: &[u8]) -> Option<Self> {
+        if digits.len() != 6 && digits.len() != 9 {
+            return None;
+        }
+
+        // ABC-DEF-GHI: AB IS THE VALUE, FCDE THE ADDRESS WITH F INVERTED, GI THE SCRAMBLED COMPARE VALUE
+        let value = digits[0] << 4 | digits[1];
+        let address = u16::from_be_bytes([
+            (digits[5] ^ 0xF) << 4 | digits[2],
+            digits[3] << 4 | digits[4],
+        ]);
+        // THE GAME GENIE SITS BETWEEN THE CONSOLE AND THE CARTRIDGE AND CAN ONLY PATCH ROM
+        if address >= 0x8000 {
+            return None;
+        }
+        let compare = if digits.len() == 9 {
+            Some((digits[6] << 4 | digits[8]).rotate_right(2) ^ 0xBA)
+        } else {
+            None
+        };
+        Some(CheatCode::GameGenie {
+            address,
+            value,
+            compare,
+        })
+    }
+
+    fn parse_game_shark(digits: &[u8]) -> Option<Self> {
+        if digits.len() != 8 {
+            return None;
+        }
+
+        // TTVVLLHH: BANK TYPE, VALUE, LOW AND HIGH BYTE OF THE ADDRESS
+        let bytes: Vec<u8> = digits
+            .chunks(2)
+            .map(|pair| pair[0] << 4 | pair[1])
+            .collect();
+        match bytes[0] {
+            0x00 | 0x01 | 0x80..=0x8F | 0x90..=0x97 => Some(CheatCode::GameShark {
+                bank: bytes[0],
+                address: u16::from_le_bytes([bytes[2], bytes[3]]),
+                value: bytes[1],
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// A named cheat made of one or more codes that are switched on and off together
+#[derive(Clone, Debug)]
+pub struct Cheat {
+    pub name: String,
+    pub codes: Vec<String>,
+    enabled: bool,
+    decoded: Vec<CheatCode>,
+}
+
+impl Cheat {
+    pub fn new(name: &str, codes: &[&str]) -> Result<Self, CheatError> {
+        let decoded = codes
+            .iter()
+            .map(|code| CheatCode::parse(code))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self {
+            name: name.to_string(),
+            codes: codes
+                .iter()
+                .map(|code| code.trim().to_uppercase())
+                .collect(),
+            enabled: true,
+            decoded,
+        })
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Only affects cheats that were not added to a `CheatEngine` yet, use the engine to switch added cheats.
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    pub fn decoded_codes(&self) -> &[CheatCode] {
+        &self.decoded
+    }
+}
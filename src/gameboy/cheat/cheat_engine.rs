@@ -0,0 +1,109 @@
+use crate::gameboy::cheat::cheat::{Cheat, CheatCode};
+
+/// Applies the enabled cheats to the running game
+///
+/// Every added cheat gets an id that stays valid until the cheat is removed. The codes of the enabled cheats are collected into lookup
+/// lists whenever a cheat changes, so the hooks in the MMU don't have to walk all cheats on every read.
+pub struct CheatEngine {
+    cheats: Vec<(usize, Cheat)>,
+    next_id: usize,
+    rom_patches: Vec<(u16, u8, Option<u8>)>,
+    ram_writes: Vec<(u16, u8)>,
+}
+
+impl CheatEngine {
+    pub fn new() -> Self {
+        Self {
+            cheats: Vec::new(),
+            next_id: 0,
+            rom_patches: Vec::new(),
+            ram_writes: Vec::new(),
+        }
+    }
+
+    /// Adds the cheat and returns its id.
+    pub fn add(&mut self, cheat: Cheat) -> usize {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.cheats.push((id, cheat));
+        self.update();
+        id
+    }
+
+    /// Returns the removed cheat or `None` if there is no cheat with the given id.
+    pub fn remove(&mut self, id: usize) -> Option<Cheat> {
+        let index = self
+            .cheats
+            .iter()
+            .position(|(cheat_id, _)| *cheat_id == id)?;
+        let (_, cheat) = self.cheats.remove(index);
+        self.update();
+        Some(cheat)
+    }
+
+    pub fn clear(&mut self) {
+        self.cheats.clear();
+        self.update();
+    }
+
+    /// Returns false if there is no cheat with the given id.
+    pub fn enable(&mut self, id: usize) -> bool {
+        self.set_enabled(id, true)
+    }
+
+    /// Returns false if there is no cheat with the given id.
+    pub fn disable(&mut self, id: usize) -> bool {
+        self.set_enabled(id, false)
+    }
+
+    fn set_enabled(&mut self, id: usize, enabled: bool) -> bool {
+        let Some((_, cheat)) = self.cheats.iter_mut().find(|(cheat_id, _)| *cheat_id == id) else {
+            return false;
+        };
+        cheat.set_enabled(enabled);
+        self.update();
+        true
+    }
+
+    /// Returns all cheats with their ids in the order they were added.
+    pub fn cheats(&self) -> impl Iterator<Item = (usize, &Cheat)> {
+        self.cheats.iter().map(|(id, cheat)| (*id, cheat))
+    }
+
+    /// Returns the value a ROM read returns with the Game Genie codes applied.
+    pub fn patch_read(&self, address: u16, value: u8) -> u8 {
+        for &(patch_address, patch_value, compare) in self.rom_patches.iter() {
+            if patch_address == address && (compare.is_none() || compare == Some(value)) {
+                return patch_value;
+            }
+        }
+        value
+    }
+
+    /// Returns the GameShark writes that have to be done once per frame.
+    pub fn ram_writes(&self) -> &[(u16, u8)] {
+        &self.ram_writes
+    }
+
+    fn update(&mut self) {
+        self.rom_patches.clear();
+        self.ram_writes.clear();
+        let codes = self
+            .cheats
+            .iter()
+            .filter(|(_, cheat)| cheat.is_enabled())
+            .flat_map(|(_, cheat)| cheat.decoded_codes());
+        for code in codes {
+            match *code {
+                CheatCode::GameGenie {
+                    address,
+                    value,
+                    compare,
+                } => self.rom_patches.push((address, value, compare)),
+                CheatCode::GameShark { address, value, .. } => {
+                    self.ram_writes.push((address, value))
+                }
+            }
+        }
+    }
+}
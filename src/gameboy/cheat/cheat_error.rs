@@ -0,0 +1,35 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io;
+
+/// Errors that can occur while parsing cheat codes or cheat files
+#[derive(Debug)]
+pub enum CheatError {
+    /// The cheat file could not be read or written
+    Io { path: String, error: io::Error },
+    /// The code is neither a Game Genie nor a GameShark code
+    InvalidCode(String),
+    /// A line of the cheat file doesn't follow the cheat file format
+    InvalidLine { line: usize, reason: String },
+}
+
+impl Display for CheatError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CheatError::Io { path, error } => write!(f, "Error accessing file {}: {}", path, error),
+            CheatError::InvalidCode(code) => write!(f, "Invalid cheat code: {}", code),
+            CheatError::InvalidLine { line, reason } => {
+                write!(f, "Invalid cheat file line {}: {}", line, reason)
+            }
+        }
+    }
+}
+
+impl Error for CheatError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CheatError::Io { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
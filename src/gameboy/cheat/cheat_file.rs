@@ -0,0 +1,73 @@
+use std::fs;
+use std::path::Path;
+
+use crate::gameboy::cheat::cheat::Cheat;
+use crate::gameboy::cheat::cheat_error::CheatError;
+
+/// Cheat files share the name of the ROM
+pub const CHEAT_EXTENSION: &str = "cht";
+
+/// Returns the path of the cheat file that belongs to the given ROM if there is one.
+pub fn find_cheat_file(rom_path: &str) -> Option<String> {
+    let cheat_path = Path::new(rom_path).with_extension(CHEAT_EXTENSION);
+    if cheat_path.is_file() {
+        Some(cheat_path.to_string_lossy().into())
+    } else {
+        None
+    }
+}
+
+pub fn load(path: &str) -> Result<Vec<Cheat>, CheatError> {
+    let text = fs::read_to_string(path).map_err(|error| CheatError::Io {
+        path: path.to_string(),
+        error,
+    })?;
+    parse(&text)
+}
+
+/// Parses a cheat file. Every line holds one cheat, e.g. `[x] Infinite lives = 00A-17B-C49` or `[ ] Max money = 01998FD1, 01998ED1`.
+/// The box in front of the name tells whether the cheat is enabled, lines starting with # are comments.
+pub fn parse(text: &str) -> Result<Vec<Cheat>, CheatError> {
+    let mut cheats = Vec::new();
+    for (index, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let invalid = |reason: &str| CheatError::InvalidLine {
+            line: index + 1,
+            reason: reason.to_string(),
+        };
+        let (enabled, rest) = if let Some(rest) = line.strip_prefix("[x]") {
+            (true, rest)
+        } else if let Some(rest) = line.strip_prefix("[ ]") {
+            (false, rest)
+        } else {
+            return Err(invalid("expected [x] or [ ] at the start of the line"));
+        };
+        let (name, codes) = rest
+            .rsplit_once('=')
+            .ok_or_else(|| invalid("expected = between name and codes"))?;
+        let codes: Vec<&str> = codes.split(',').map(str::trim).collect();
+
+        let mut cheat =
+            Cheat::new(name.trim(), &codes).map_err(|error| invalid(&error.to_string()))?;
+        cheat.set_enabled(enabled);
+        cheats.push(cheat);
+    }
+    Ok(cheats)
+}
+
+pub fn save<'a>(path: &str, cheats: impl Iterator<Item = &'a Cheat>) -> Result<(), CheatError> {
+    let text: String = cheats
+        .map(|cheat| {
+            let checkbox = if cheat.is_enabled() { "[x]" } else { "[ ]" };
+            format!("{} {} = {}\n", checkbox, cheat.name, cheat.codes.join(", "))
+        })
+        .collect();
+    fs::write(path, text).map_err(|error| CheatError::Io {
+        path: path.to_string(),
+        error,
+    })
+}
@@ -0,0 +1,191 @@
+use std::ops::RangeInclusive;
+
+use crate::gameboy::cheat::cheat::Cheat;
+use crate::gameboy::cheat::cheat_error::CheatError;
+use crate::gameboy::memory::memory::Memory;
+
+/// Cartridge RAM, WRAM and HRAM are searched, everything else is either ROM or hardware registers
+const SEARCH_REGIONS: [RangeInclusive<u16>; 3] =
+    [0xA000..=0xBFFF, 0xC000..=0xDFFF, 0xFF80..=0xFFFE];
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum ValueSize {
+    Byte,
+    /// Two bytes in little endian order
+    Word,
+}
+
+impl ValueSize {
+    fn len(&self) -> u16 {
+        match self {
+            ValueSize::Byte => 1,
+            ValueSize::Word => 2,
+        }
+    }
+
+    fn read(&self, memory: &dyn Memory, address: u16) -> u16 {
+        match self {
+            ValueSize::Byte => memory.read_byte(address) as u16,
+            ValueSize::Word => {
+                u16::from_le_bytes([memory.read_byte(address), memory.read_byte(address + 1)])
+            }
+        }
+    }
+}
+
+/// Compares the current value of a candidate with its value at the previous search step or with a fixed value
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum SearchFilter {
+    Equal,
+    Changed,
+    Increased,
+    Decreased,
+    EqualTo(u16),
+}
+
+impl SearchFilter {
+    fn matches(&self, previous: u16, current: u16) -> bool {
+        match *self {
+            SearchFilter::Equal => current == previous,
+            SearchFilter::Changed => current != previous,
+            SearchFilter::Increased => current > previous,
+            SearchFilter::Decreased => current < previous,
+            SearchFilter::EqualTo(value) => current == value,
+        }
+    }
+}
+
+/// An address that passed all filters so far
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct SearchResult {
+    pub address: u16,
+    pub size: ValueSize,
+    pub value: u16,
+}
+
+impl SearchResult {
+    /// Creates a cheat that keeps the value at the given one, as GameShark codes.
+    pub fn to_cheat(self, name: &str, value: u16) -> Result<Cheat, CheatError> {
+        let codes: Vec<String> = value.to_le_bytes()[0..self.size.len() as usize]
+            .iter()
+            .enumerate()
+            .map(|(offset, byte)| {
+                let [low, high] = (self.address + offset as u16).to_le_bytes();
+                format!("01{:02X}{:02X}{:02X}", byte, low, high)
+            })
+            .collect();
+        let codes: Vec<&str> = codes.iter().map(String::as_str).collect();
+        Cheat::new(name, &codes)
+    }
+
+    pub fn to_watch(self) -> Watch {
+        Watch {
+            address: self.address,
+            size: self.size,
+        }
+    }
+}
+
+/// Shows the live value of an address, e.g. in a debugger window
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Watch {
+    pub address: u16,
+    pub size: ValueSize,
+}
+
+impl Watch {
+    pub fn read(&self, memory: &dyn Memory) -> u16 {
+        self.size.read(memory, self.address)
+    }
+}
+
+/// Finds the address of a value like the lives or the health by narrowing down all addresses step by step
+///
+/// The search starts with a snapshot of every searched address. Each call to `filter` compares the current values with the values of the
+/// previous step and drops the addresses that don't match, e.g. filtering for `Decreased` after losing a life.
+pub struct MemorySearch {
+    size: ValueSize,
+    candidates: Vec<SearchResult>,
+}
+
+impl MemorySearch {
+    /// Takes the initial snapshot, usually of the `Mmu`.
+    pub fn new(memory: &dyn Memory, size: ValueSize) -> Self {
+        let candidates = SEARCH_REGIONS
+            .iter()
+            .flat_map(|region| *region.start()..=*region.end() + 1 - size.len())
+            .map(|address| SearchResult {
+                address,
+                size,
+                value: size.read(memory, address),
+            })
+            .collect();
+        Self { size, candidates }
+    }
+
+    /// Drops all candidates that don't match the filter and remembers the current values for the next step.
+    pub fn filter(&mut self, memory: &dyn Memory, filter: SearchFilter) {
+        let size = self.size;
+        self.candidates.retain_mut(|candidate| {
+            let current = size.read(memory, candidate.address);
+            let matches = filter.matches(candidate.value, current);
+            candidate.value = current;
+            matches
+        });
+    }
+
+    pub fn results(&self) -> &[SearchResult] {
+        &self.candidates
+    }
+
+    pub fn len(&self) -> usize {
+        self.candidates.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.candidates.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::gameboy::cheat::memory_search::{SearchFilter, ValueSize};
+    use crate::gameboy::gameboy::Gameboy;
+    use crate::gameboy::memory::memory::Memory;
+
+    #[test]
+    fn finds_changed_value_and_freezes_it() {
+        let mut gameboy = Gameboy::from_rom_data(&[0; 0x8000]).unwrap();
+        gameboy.mmu.write_byte(0xC100, 3);
+        gameboy.mmu.write_byte(0xC200, 3);
+        gameboy.start_memory_search(ValueSize::Byte);
+
+        gameboy.mmu.write_byte(0xC100, 4);
+        gameboy.mmu.write_byte(0xC200, 2);
+        let results = gameboy.filter_memory_search(SearchFilter::Increased);
+        let addresses: Vec<u16> = results.iter().map(|result| result.address).collect();
+        assert_eq!(addresses, [0xC100]);
+
+        let results = gameboy.filter_memory_search(SearchFilter::EqualTo(4));
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].value, 4);
+
+        let cheat = results[0].to_cheat("Lives", 9).unwrap();
+        assert_eq!(cheat.codes, ["010900C1"]);
+    }
+
+    #[test]
+    fn word_cheat_writes_both_bytes() {
+        let mut gameboy = Gameboy::from_rom_data(&[0; 0x8000]).unwrap();
+        gameboy.start_memory_search(ValueSize::Word);
+        gameboy.mmu.write_byte(0xFF90, 0x34);
+        gameboy.mmu.write_byte(0xFF91, 0x12);
+
+        let results = gameboy.filter_memory_search(SearchFilter::EqualTo(0x1234));
+        let addresses: Vec<u16> = results.iter().map(|result| result.address).collect();
+        assert_eq!(addresses, [0xFF90]);
+
+        let cheat = results[0].to_cheat("Money", 0x0999).unwrap();
+        assert_eq!(cheat.codes, ["019990FF", "010991FF"]);
+    }
+}
@@ -0,0 +1,5 @@
+pub mod cheat;
+pub mod cheat_engine;
+pub mod cheat_error;
+pub mod cheat_file;
+pub mod memory_search;
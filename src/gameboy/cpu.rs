@@ -1,95 +1,207 @@
 use strum::IntoEnumIterator;
 
-use crate::gameboy::cpu::instructions::decode;
+use crate::gameboy::cpu::bus::Bus;
+use crate::gameboy::cpu::cpu_event::CpuEvent;
 use crate::gameboy::cpu::interrupt::Interrupt;
 use crate::gameboy::cpu::registers::Registers;
-use crate::gameboy::memory::memory;
-use crate::gameboy::memory::memory::Memory;
-use crate::gameboy::memory::mmu::Mmu;
+use crate::gameboy::cpu::trace::Tracer;
+use crate::gameboy::model::Model;
 
-mod instructions;
+pub mod bus;
+pub mod cpu_event;
+pub mod disassembler;
+pub mod instructions;
 pub mod interrupt;
 mod registers;
+#[cfg(test)]
+mod single_step_tests;
+pub mod trace;
 
 pub struct Cpu {
     register: Registers,
-    cycle_accumulator: isize,
+    instruction_cycles: isize,
     halted: bool,
+    halt_bug: bool,
+    stopped: bool,
+    locked: bool,
+    events: Vec<CpuEvent>,
+    tracer: Option<Box<Tracer>>,
 }
 
 impl Cpu {
     pub fn new() -> Self {
         Self {
             register: Registers::new(),
-            cycle_accumulator: 0,
+            instruction_cycles: 0,
             halted: false,
+            halt_bug: false,
+            stopped: false,
+            locked: false,
+            events: Vec::new(),
+            tracer: None,
         }
     }
 
-    pub fn step(&mut self, mmu: &mut Mmu) {
-        self.cycle_accumulator += 4;
-
-        if self.cycle_accumulator > 0 {
-            self.register.step();
+    /// Runs one instruction, serves one interrupt or idles for one M-cycle while halted. The rest of the system is stepped along with
+    /// every memory access, so each access happens in the M-cycle it takes on hardware.
+    pub fn step<B: Bus>(&mut self, bus: &mut B) {
+        // A LOCKED UP CPU NEITHER FETCHES INSTRUCTIONS NOR SERVES INTERRUPTS, BUT THE REST OF THE SYSTEM KEEPS RUNNING
+        if self.locked {
+            self.tick(bus);
+            return;
+        }
 
-            if self.handle_interrupts(mmu) {
+        // ONLY A JOYPAD LINE GOING LOW WAKES THE CPU FROM STOP MODE
+        if self.stopped {
+            if !bus.is_joypad_line_low() {
                 return;
             }
+            self.stopped = false;
+        }
 
-            if self.halted {
-                self.cycle_accumulator -= 4;
-                return;
+        self.register.step();
+
+        if self.handle_interrupts(bus) {
+            return;
+        }
+
+        if self.halted {
+            self.tick(bus);
+            return;
+        }
+
+        // TRACING STOPS WHEN THE OUTPUT FAILS
+        if let Some(tracer) = self.tracer.as_mut() {
+            if let Err(error) = tracer.trace(&self.register, bus) {
+                println!("Stopped tracing: {error}");
+                self.tracer = None;
             }
+        }
 
-            let op_code = mmu.read_byte(self.register.pc);
-            self.register.pc += 1;
+        // AFTER THE HALT BUG, PC FAILS TO ADVANCE AND THE NEXT BYTE IS READ TWICE
+        self.instruction_cycles = 0;
+        let op_code = self.read_byte(bus, self.register.pc);
+        if self.halt_bug {
+            self.halt_bug = false;
+        } else {
+            self.register.pc = self.register.pc.wrapping_add(1);
+        }
 
-            self.cycle_accumulator -= self.run_instruction(mmu, op_code);
+        // THE INTERNAL CYCLES WITHOUT MEMORY ACCESS ARE LEFT
+        let cycles = self.run_instruction(bus, op_code);
+        while self.instruction_cycles < cycles {
+            self.tick(bus);
         }
     }
 
-    fn handle_interrupts(&mut self, mmu: &mut Mmu) -> bool {
+    fn handle_interrupts<B: Bus>(&mut self, bus: &mut B) -> bool {
         let was_halted = self.halted;
-        let enabled_register = mmu.read_byte(memory::IE);
-        let flag_register = mmu.read_byte(memory::IF);
-        if enabled_register > 0 && flag_register > 0 {
-            for interrupt in Interrupt::iter() {
-                let mask = interrupt.flag_mask();
-                if (enabled_register & mask) > 0 && (flag_register & mask) > 0 {
-                    self.halted = false;
-                    if self.register.is_interrupts_enabled() {
-                        // PUSH PC TO THE STACK
-                        self.register.sp -= 1;
-                        mmu.write_byte(self.register.sp, (self.register.pc >> 8) as u8);
-                        self.register.sp -= 1;
-                        mmu.write_byte(self.register.sp, self.register.pc as u8);
-
-                        // JUMP
-                        self.register.pc = interrupt.jump_address();
-
-                        // CLEAR IME AND IF
-                        self.register.set_interrupts_enabled(false, false);
-                        mmu.set_bit(memory::IF, interrupt.bit_number(), false);
-
-                        self.cycle_accumulator -= 20;
-                        if was_halted {
-                            self.cycle_accumulator -= 4;
-                        }
-
-                        return true;
-                    }
-                }
-            }
+        if bus.pending_interrupts() == 0 {
+            return false;
         }
 
-        if was_halted && !self.halted {
-            self.cycle_accumulator -= 4;
+        // A PENDING INTERRUPT ENDS HALT MODE EVEN IF IME IS NOT SET, WAKING UP TAKES ONE M-CYCLE
+        self.halted = false;
+        if was_halted {
+            self.tick(bus);
         }
+        if !self.register.is_interrupts_enabled() {
+            return false;
+        }
+
+        // TWO INTERNAL CYCLES, THEN PUSH PC TO THE STACK
+        self.tick(bus);
+        self.tick(bus);
+        self.register.sp = self.register.sp.wrapping_sub(1);
+        self.write_byte(bus, self.register.sp, (self.register.pc >> 8) as u8);
+
+        // THE INTERRUPT IS ONLY CHOSEN AFTER THE HIGH BYTE IS PUSHED, SO A PUSH TO IE CAN CHANGE OR CANCEL IT
+        let pending = bus.pending_interrupts();
+        self.register.sp = self.register.sp.wrapping_sub(1);
+        self.write_byte(bus, self.register.sp, self.register.pc as u8);
+
+        // JUMP AND CLEAR IF, A CANCELLED DISPATCH JUMPS TO 0x0000 INSTEAD
+        match Interrupt::iter().find(|interrupt| pending & interrupt.flag_mask() > 0) {
+            Some(interrupt) => {
+                self.register.pc = interrupt.jump_address();
+                bus.acknowledge_interrupt(interrupt);
+            }
+            None => self.register.pc = 0x0000,
+        }
+        self.tick(bus);
+
+        // CLEAR IME
+        self.register.set_interrupts_enabled(false, false);
+
+        true
+    }
+
+    /// Advances the rest of the system by one M-cycle.
+    fn tick<B: Bus>(&mut self, bus: &mut B) {
+        bus.tick();
+        self.instruction_cycles += 4;
+    }
+
+    /// Reads a byte at the end of an M-cycle.
+    fn read_byte<B: Bus>(&mut self, bus: &mut B, address: u16) -> u8 {
+        self.tick(bus);
+        bus.read(address)
+    }
 
-        false
+    /// Reads the low byte first, taking one M-cycle per byte.
+    fn read_word<B: Bus>(&mut self, bus: &mut B, address: u16) -> u16 {
+        let low = self.read_byte(bus, address);
+        let high = self.read_byte(bus, address.wrapping_add(1));
+        u16::from_le_bytes([low, high])
     }
 
-    pub fn halt(&mut self) {
+    /// Writes a byte at the end of an M-cycle.
+    fn write_byte<B: Bus>(&mut self, bus: &mut B, address: u16, value: u8) {
+        self.tick(bus);
+        bus.write(address, value);
+    }
+
+    /// With IME unset and an interrupt already pending, HALT doesn't halt at all. Instead the HALT bug makes the CPU read the next
+    /// byte twice.
+    pub fn halt<B: Bus>(&mut self, bus: &B) {
+        if !self.register.is_interrupts_enabled() && bus.pending_interrupts() > 0 {
+            self.halt_bug = true;
+            return;
+        }
         self.halted = true;
     }
+
+    pub fn is_stopped(&self) -> bool {
+        self.stopped
+    }
+
+    /// Returns the events that occurred since the last call.
+    pub fn take_events(&mut self) -> Vec<CpuEvent> {
+        std::mem::take(&mut self.events)
+    }
+
+    /// Traces every instruction from now on, `None` turns tracing off.
+    pub fn set_tracer(&mut self, tracer: Option<Tracer>) {
+        self.tracer = tracer.map(Box::new);
+    }
+
+    pub fn get_tracer(&self) -> Option<&Tracer> {
+        self.tracer.as_deref()
+    }
+
+    /// Sets the registers to the values the boot ROM of the given model leaves behind and continues at the cartridge's entry point.
+    pub fn skip_boot(&mut self, model: Model) {
+        let (af, bc, de, hl) = match model {
+            Model::Dmg => (0x01B0, 0x0013, 0x00D8, 0x014D),
+            Model::Mgb => (0xFFB0, 0x0013, 0x00D8, 0x014D),
+            Model::Sgb => (0x0100, 0x0014, 0x0000, 0xC060),
+            Model::Cgb => (0x1180, 0x0000, 0xFF56, 0x000D),
+        };
+        self.register.set_af(af);
+        self.register.set_bc(bc);
+        self.register.set_de(de);
+        self.register.set_hl(hl);
+        self.register.sp = 0xFFFE;
+        self.register.pc = 0x0100;
+    }
 }
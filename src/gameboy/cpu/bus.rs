@@ -0,0 +1,61 @@
+use crate::gameboy::cpu::interrupt::Interrupt;
+
+#[cfg(test)]
+pub mod flat_bus;
+#[cfg(test)]
+pub mod recording_bus;
+pub mod tracing_bus;
+
+/// Everything the CPU is connected to
+///
+/// The CPU calls `tick` at the start of every M-cycle and does its read or write at the end of it, so the rest of the system runs
+/// along with each access. `Mmu` is the whole Game Boy and `TracingBus` wraps it to log the CPU's accesses. The tests run the CPU on
+/// plain RAM with `FlatBus` and record its activity with `RecordingBus`. The hooks for interrupts, STOP and tracing default to a
+/// system without an interrupt controller, timer or cartridge.
+pub trait Bus {
+    /// Advances the rest of the system by one M-cycle.
+    fn tick(&mut self);
+
+    /// Reads a byte the way the CPU sees it.
+    fn read(&mut self, address: u16) -> u8;
+
+    /// Writes a byte the way the CPU does.
+    fn write(&mut self, address: u16, value: u8);
+
+    /// Reads a byte without taking time, for tracing and debugging.
+    fn peek(&self, address: u16) -> u8;
+
+    /// Returns the interrupts that are both requested and enabled.
+    fn pending_interrupts(&self) -> u8 {
+        0
+    }
+
+    /// Clears the request flag of an interrupt that is being served.
+    fn acknowledge_interrupt(&mut self, _interrupt: Interrupt) {}
+
+    /// Returns the number of the 16 KiB ROM bank mapped at the given address in 0x0000-0x7FFF.
+    fn get_rom_bank(&self, address: u16) -> usize {
+        if address < 0x4000 {
+            0
+        } else {
+            1
+        }
+    }
+
+    /// Returns true while a button is held on a selected joypad line, which ends STOP mode.
+    fn is_joypad_line_low(&self) -> bool {
+        false
+    }
+
+    fn reset_divider(&mut self) {}
+
+    /// Returns true if the CGB speed switch is armed, the next STOP then performs it.
+    fn is_speed_switch_armed(&self) -> bool {
+        false
+    }
+
+    fn switch_speed(&mut self) {}
+
+    /// Blanks the display when STOP mode turns it off.
+    fn clear_screen(&mut self) {}
+}
@@ -0,0 +1,37 @@
+use crate::gameboy::cpu::bus::Bus;
+
+/// 64 KiB of plain RAM without any IO registers, cartridge or timing, e.g. to test the CPU on its own
+pub struct FlatBus {
+    memory: Box<[u8; 0x10000]>,
+}
+
+impl FlatBus {
+    pub fn new() -> Self {
+        Self {
+            memory: Box::new([0; 0x10000]),
+        }
+    }
+
+    /// Copies the data to memory starting at the given address, wrapping around at the end of the address space.
+    pub fn load(&mut self, address: u16, data: &[u8]) {
+        for (offset, &value) in data.iter().enumerate() {
+            self.memory[address.wrapping_add(offset as u16) as usize] = value;
+        }
+    }
+}
+
+impl Bus for FlatBus {
+    fn tick(&mut self) {}
+
+    fn read(&mut self, address: u16) -> u8 {
+        self.memory[address as usize]
+    }
+
+    fn write(&mut self, address: u16, value: u8) {
+        self.memory[address as usize] = value;
+    }
+
+    fn peek(&self, address: u16) -> u8 {
+        self.memory[address as usize]
+    }
+}
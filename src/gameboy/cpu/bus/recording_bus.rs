@@ -0,0 +1,95 @@
+use crate::gameboy::cpu::bus::Bus;
+use crate::gameboy::cpu::interrupt::Interrupt;
+
+/// What the CPU did on the bus during one M-cycle
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum BusCycle {
+    Idle,
+    Read { address: u16, value: u8 },
+    Write { address: u16, value: u8 },
+}
+
+/// Passes everything on to another bus and records the CPU's activity of every M-cycle
+pub struct RecordingBus<B: Bus> {
+    inner: B,
+    cycles: Vec<BusCycle>,
+}
+
+impl<B: Bus> RecordingBus<B> {
+    pub fn new(inner: B) -> Self {
+        Self {
+            inner,
+            cycles: Vec::new(),
+        }
+    }
+
+    pub fn inner_mut(&mut self) -> &mut B {
+        &mut self.inner
+    }
+
+    /// Returns the M-cycles recorded since the last call.
+    pub fn take_cycles(&mut self) -> Vec<BusCycle> {
+        std::mem::take(&mut self.cycles)
+    }
+
+    /// The CPU ticks before each access, so the access belongs to the last M-cycle.
+    fn record(&mut self, cycle: BusCycle) {
+        if let Some(last) = self.cycles.last_mut() {
+            *last = cycle;
+        }
+    }
+}
+
+impl<B: Bus> Bus for RecordingBus<B> {
+    fn tick(&mut self) {
+        self.cycles.push(BusCycle::Idle);
+        self.inner.tick();
+    }
+
+    fn read(&mut self, address: u16) -> u8 {
+        let value = self.inner.read(address);
+        self.record(BusCycle::Read { address, value });
+        value
+    }
+
+    fn write(&mut self, address: u16, value: u8) {
+        self.inner.write(address, value);
+        self.record(BusCycle::Write { address, value });
+    }
+
+    fn peek(&self, address: u16) -> u8 {
+        self.inner.peek(address)
+    }
+
+    fn pending_interrupts(&self) -> u8 {
+        self.inner.pending_interrupts()
+    }
+
+    fn acknowledge_interrupt(&mut self, interrupt: Interrupt) {
+        self.inner.acknowledge_interrupt(interrupt);
+    }
+
+    fn get_rom_bank(&self, address: u16) -> usize {
+        self.inner.get_rom_bank(address)
+    }
+
+    fn is_joypad_line_low(&self) -> bool {
+        self.inner.is_joypad_line_low()
+    }
+
+    fn reset_divider(&mut self) {
+        self.inner.reset_divider();
+    }
+
+    fn is_speed_switch_armed(&self) -> bool {
+        self.inner.is_speed_switch_armed()
+    }
+
+    fn switch_speed(&mut self) {
+        self.inner.switch_speed();
+    }
+
+    fn clear_screen(&mut self) {
+        self.inner.clear_screen();
+    }
+}
@@ -0,0 +1,99 @@
+use std::io;
+use std::io::Write;
+use std::ops::RangeInclusive;
+
+use crate::gameboy::cpu::bus::Bus;
+use crate::gameboy::cpu::interrupt::Interrupt;
+
+/// Passes everything on to another bus and logs the CPU's reads and writes in an address range, e.g. to find the code that changes a
+/// variable
+///
+/// It borrows the bus and the output, so it can wrap the `Mmu` for a single step.
+pub struct TracingBus<'a, B: Bus> {
+    inner: &'a mut B,
+    addresses: RangeInclusive<u16>,
+    output: &'a mut dyn Write,
+    error: Option<io::Error>,
+}
+
+impl<'a, B: Bus> TracingBus<'a, B> {
+    pub fn new(
+        inner: &'a mut B,
+        addresses: RangeInclusive<u16>,
+        output: &'a mut dyn Write,
+    ) -> Self {
+        Self {
+            inner,
+            addresses,
+            output,
+            error: None,
+        }
+    }
+
+    /// Returns the error if writing to the output failed, later accesses weren't logged then.
+    pub fn take_error(&mut self) -> Option<io::Error> {
+        self.error.take()
+    }
+
+    fn log(&mut self, access: &str, address: u16, value: u8) {
+        if self.error.is_some() || !self.addresses.contains(&address) {
+            return;
+        }
+        if let Err(error) = writeln!(self.output, "{access} 0x{value:02X} at 0x{address:04X}") {
+            self.error = Some(error);
+        }
+    }
+}
+
+impl<B: Bus> Bus for TracingBus<'_, B> {
+    fn tick(&mut self) {
+        self.inner.tick();
+    }
+
+    fn read(&mut self, address: u16) -> u8 {
+        let value = self.inner.read(address);
+        self.log("Read", address, value);
+        value
+    }
+
+    fn write(&mut self, address: u16, value: u8) {
+        self.log("Write", address, value);
+        self.inner.write(address, value);
+    }
+
+    fn peek(&self, address: u16) -> u8 {
+        self.inner.peek(address)
+    }
+
+    fn pending_interrupts(&self) -> u8 {
+        self.inner.pending_interrupts()
+    }
+
+    fn acknowledge_interrupt(&mut self, interrupt: Interrupt) {
+        self.inner.acknowledge_interrupt(interrupt);
+    }
+
+    fn get_rom_bank(&self, address: u16) -> usize {
+        self.inner.get_rom_bank(address)
+    }
+
+    fn is_joypad_line_low(&self) -> bool {
+        self.inner.is_joypad_line_low()
+    }
+
+    fn reset_divider(&mut self) {
+        self.inner.reset_divider();
+    }
+
+    fn is_speed_switch_armed(&self) -> bool {
+        self.inner.is_speed_switch_armed()
+    }
+
+    fn switch_speed(&mut self) {
+        self.inner.switch_speed();
+    }
+
+    fn clear_screen(&mut self) {
+        self.inner.clear_screen();
+    }
+}
@@ -0,0 +1,19 @@
+use std::fmt::{Display, Formatter};
+
+/// Something the CPU ran into that the frontend or a debugger may want to report
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum CpuEvent {
+    /// An illegal opcode hung the CPU, only a reset recovers from this
+    LockedUp { op_code: u8, address: u16 },
+}
+
+impl Display for CpuEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CpuEvent::LockedUp { op_code, address } => write!(
+                f,
+                "CPU locked up on illegal opcode 0x{op_code:02X} at 0x{address:04X}"
+            ),
+        }
+    }
+}
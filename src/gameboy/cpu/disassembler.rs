@@ -0,0 +1,115 @@
+use std::fmt::{Display, Formatter};
+
+use crate::gameboy::cpu::instructions::instruction::{Instruction, Operation};
+use crate::gameboy::cpu::instructions::instruction_table::{CB_INSTRUCTIONS, INSTRUCTIONS};
+use crate::gameboy::memory::memory::Memory;
+
+/// A decoded instruction, e.g. `LD A,(HL+)` or `JR NZ,$FB`
+#[derive(Clone, PartialEq, Debug)]
+pub struct DisassembledInstruction {
+    pub address: u16,
+    pub bytes: Vec<u8>,
+    pub text: String,
+    /// The address a jump, call or restart continues at if it is taken. Jumps to HL and returns have no fixed target.
+    pub jump_target: Option<u16>,
+}
+
+impl DisassembledInstruction {
+    pub fn length(&self) -> u16 {
+        self.bytes.len() as u16
+    }
+
+    /// Returns the address of the instruction that follows in memory.
+    pub fn next_address(&self) -> u16 {
+        self.address.wrapping_add(self.length())
+    }
+}
+
+impl Display for DisassembledInstruction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let bytes: Vec<String> = self
+            .bytes
+            .iter()
+            .map(|byte| format!("{byte:02X}"))
+            .collect();
+        write!(
+            f,
+            "{:04X}  {:<8}  {}",
+            self.address,
+            bytes.join(" "),
+            self.text
+        )
+    }
+}
+
+/// Decodes the instruction at the given address. Reading the bytes goes through `Memory::read_byte`, so it doesn't step the system.
+pub fn disassemble(memory: &dyn Memory, address: u16) -> DisassembledInstruction {
+    let op_code = memory.read_byte(address);
+    let instruction = if op_code == 0xCB {
+        &CB_INSTRUCTIONS[memory.read_byte(address.wrapping_add(1)) as usize]
+    } else {
+        &INSTRUCTIONS[op_code as usize]
+    };
+
+    let bytes: Vec<u8> = (0..instruction.length as u16)
+        .map(|offset| memory.read_byte(address.wrapping_add(offset)))
+        .collect();
+    DisassembledInstruction {
+        address,
+        text: format_text(instruction, &bytes),
+        jump_target: jump_target(instruction, address, &bytes),
+        bytes,
+    }
+}
+
+/// Decodes `count` instructions that follow each other in memory, starting at the given address.
+pub fn disassemble_range(
+    memory: &dyn Memory,
+    address: u16,
+    count: usize,
+) -> Vec<DisassembledInstruction> {
+    let mut address = address;
+    let mut result = Vec::with_capacity(count);
+    for _ in 0..count {
+        let instruction = disassemble(memory, address);
+        address = instruction.next_address();
+        result.push(instruction);
+    }
+    result
+}
+
+/// Replaces the operand placeholder of the mnemonic with the bytes following the opcode.
+fn format_text(instruction: &Instruction, bytes: &[u8]) -> String {
+    let mnemonic = instruction.mnemonic;
+    if instruction.operation == Operation::Illegal {
+        return format!("{mnemonic} ${:02X}", bytes[0]);
+    }
+
+    let word = || u16::from_le_bytes([bytes[1], bytes[2]]);
+    let (placeholder, value) = if mnemonic.contains("d16") {
+        ("d16", format!("${:04X}", word()))
+    } else if mnemonic.contains("a16") {
+        ("a16", format!("${:04X}", word()))
+    } else if mnemonic.contains("a8") {
+        ("a8", format!("$FF{:02X}", bytes[1]))
+    } else if mnemonic.contains("d8") {
+        ("d8", format!("${:02X}", bytes[1]))
+    } else if mnemonic.contains("r8") {
+        ("r8", format!("${:02X}", bytes[1]))
+    } else {
+        return mnemonic.to_string();
+    };
+    mnemonic.replace(placeholder, &value)
+}
+
+fn jump_target(instruction: &Instruction, address: u16, bytes: &[u8]) -> Option<u16> {
+    match instruction.operation {
+        Operation::Jr(_) => {
+            let offset = bytes[1] as i8 as i16;
+            Some(address.wrapping_add(2).wrapping_add_signed(offset))
+        }
+        Operation::Jp(_) | Operation::Call(_) => Some(u16::from_le_bytes([bytes[1], bytes[2]])),
+        Operation::Rst(target) => Some(target),
+        _ => None,
+    }
+}
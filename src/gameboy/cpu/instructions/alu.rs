@@ -0,0 +1,145 @@
+use crate::gameboy::cpu::instructions::instruction::{AluOp, ShiftOp};
+use crate::gameboy::cpu::registers::FlagId;
+use crate::gameboy::cpu::Cpu;
+
+impl Cpu {
+    /// Combines A with the value and stores the result in A, except for CP which only sets the flags.
+    pub fn alu(&mut self, operation: AluOp, value: u8) {
+        let a = self.register.a;
+        let carry = self.register.is_flag_set(FlagId::C) as u8;
+        let (result, half_carry, carry) = match operation {
+            AluOp::Add => (
+                a.wrapping_add(value),
+                (a & 0xF) + (value & 0xF) > 0xF,
+                a as u16 + value as u16 > 0xFF,
+            ),
+            AluOp::Adc => (
+                a.wrapping_add(value).wrapping_add(carry),
+                (a & 0xF) + (value & 0xF) + carry > 0xF,
+                a as u16 + value as u16 + carry as u16 > 0xFF,
+            ),
+            AluOp::Sub | AluOp::Cp => (a.wrapping_sub(value), (a & 0xF) < (value & 0xF), a < value),
+            AluOp::Sbc => (
+                a.wrapping_sub(value).wrapping_sub(carry),
+                (a & 0xF) < (value & 0xF) + carry,
+                (a as u16) < value as u16 + carry as u16,
+            ),
+            AluOp::And => (a & value, true, false),
+            AluOp::Xor => (a ^ value, false, false),
+            AluOp::Or => (a | value, false, false),
+        };
+
+        // SET FLAGS
+        self.register.set_flag(FlagId::Z, result == 0);
+        self.register.set_flag(
+            FlagId::N,
+            matches!(operation, AluOp::Sub | AluOp::Sbc | AluOp::Cp),
+        );
+        self.register.set_flag(FlagId::H, half_carry);
+        self.register.set_flag(FlagId::C, carry);
+
+        if operation != AluOp::Cp {
+            self.register.a = result;
+        }
+    }
+
+    pub fn inc(&mut self, value: u8) -> u8 {
+        let result = value.wrapping_add(1);
+
+        // SET FLAGS
+        self.register.set_flag(FlagId::Z, result == 0);
+        self.register.set_flag(FlagId::N, false);
+        self.register.set_flag(FlagId::H, value & 0xF == 0xF);
+
+        result
+    }
+
+    pub fn dec(&mut self, value: u8) -> u8 {
+        let result = value.wrapping_sub(1);
+
+        // SET FLAGS
+        self.register.set_flag(FlagId::Z, result == 0);
+        self.register.set_flag(FlagId::N, true);
+        self.register.set_flag(FlagId::H, value & 0xF == 0);
+
+        result
+    }
+
+    pub fn add_hl(&mut self, value: u16) {
+        let hl = self.register.get_hl();
+        self.register.set_hl(hl.wrapping_add(value));
+
+        // SET FLAGS
+        self.register.set_flag(FlagId::N, false);
+        self.register
+            .set_flag(FlagId::H, (hl & 0xFFF) + (value & 0xFFF) > 0xFFF);
+        self.register
+            .set_flag(FlagId::C, hl as u32 + value as u32 > 0xFFFF);
+    }
+
+    /// Adds the signed offset to SP. The flags are set by the unsigned addition to the lower byte of SP.
+    pub fn add_sp_offset(&mut self, offset: u8) -> u16 {
+        let sp = self.register.sp;
+
+        // SET FLAGS
+        self.register.set_flag(FlagId::Z, false);
+        self.register.set_flag(FlagId::N, false);
+        self.register
+            .set_flag(FlagId::H, (sp & 0xF) + (offset as u16 & 0xF) > 0xF);
+        self.register
+            .set_flag(FlagId::C, (sp & 0xFF) + offset as u16 > 0xFF);
+
+        sp.wrapping_add_signed(offset as i8 as i16)
+    }
+
+    pub fn shift(&mut self, operation: ShiftOp, value: u8) -> u8 {
+        let carry = self.register.is_flag_set(FlagId::C) as u8;
+        let (result, carry) = match operation {
+            ShiftOp::Rlc => (value.rotate_left(1), value & 0x80 > 0),
+            ShiftOp::Rrc => (value.rotate_right(1), value & 0x01 > 0),
+            ShiftOp::Rl => (value << 1 | carry, value & 0x80 > 0),
+            ShiftOp::Rr => (value >> 1 | carry << 7, value & 0x01 > 0),
+            ShiftOp::Sla => (value << 1, value & 0x80 > 0),
+            ShiftOp::Sra => (value >> 1 | (value & 0x80), value & 0x01 > 0),
+            ShiftOp::Swap => (value.rotate_left(4), false),
+            ShiftOp::Srl => (value >> 1, value & 0x01 > 0),
+        };
+
+        // SET FLAGS
+        self.register.set_flag(FlagId::Z, result == 0);
+        self.register.set_flag(FlagId::N, false);
+        self.register.set_flag(FlagId::H, false);
+        self.register.set_flag(FlagId::C, carry);
+
+        result
+    }
+
+    /// Adjusts A to a valid BCD number after an addition or subtraction of two BCD numbers.
+    pub fn daa(&mut self) {
+        let mut a = self.register.a;
+        let mut carry = self.register.is_flag_set(FlagId::C);
+        let half_carry = self.register.is_flag_set(FlagId::H);
+        if self.register.is_flag_set(FlagId::N) {
+            if carry {
+                a = a.wrapping_sub(0x60);
+            }
+            if half_carry {
+                a = a.wrapping_sub(0x06);
+            }
+        } else {
+            if carry || a > 0x99 {
+                a = a.wrapping_add(0x60);
+                carry = true;
+            }
+            if half_carry || a & 0xF > 0x9 {
+                a = a.wrapping_add(0x06);
+            }
+        }
+        self.register.a = a;
+
+        // SET FLAGS
+        self.register.set_flag(FlagId::Z, a == 0);
+        self.register.set_flag(FlagId::H, false);
+        self.register.set_flag(FlagId::C, carry);
+    }
+}
@@ -0,0 +1,346 @@
+use crate::gameboy::cpu::bus::Bus;
+use crate::gameboy::cpu::instructions::instruction::{
+    Condition, Instruction, Operand8, Operation, Register16,
+};
+use crate::gameboy::cpu::instructions::instruction_table::{CB_INSTRUCTIONS, INSTRUCTIONS};
+use crate::gameboy::cpu::registers::FlagId;
+use crate::gameboy::cpu::Cpu;
+
+impl Cpu {
+    /// Executes the instruction of the already fetched opcode and returns its T-cycles.
+    pub fn run_instruction<B: Bus>(&mut self, bus: &mut B, op_code: u8) -> isize {
+        self.execute(bus, &INSTRUCTIONS[op_code as usize], op_code)
+    }
+
+    fn execute<B: Bus>(&mut self, bus: &mut B, instruction: &Instruction, op_code: u8) -> isize {
+        let taken = match instruction.operation {
+            Operation::Nop => false,
+            Operation::Stop => return self.stop(bus),
+            Operation::Halt => {
+                self.halt(bus);
+                false
+            }
+            Operation::Di => {
+                self.register.set_interrupts_enabled(false, false);
+                false
+            }
+            Operation::Ei => {
+                self.register.set_interrupts_enabled(true, false);
+                false
+            }
+            Operation::Illegal => return self.lock_up(op_code),
+            Operation::Prefix => {
+                let cb_op_code = self.read_immediate(bus);
+                return self.execute(bus, &CB_INSTRUCTIONS[cb_op_code as usize], cb_op_code);
+            }
+            Operation::Load(target, source) => {
+                let value = self.read_operand(bus, source);
+                self.write_operand(bus, target, value);
+                false
+            }
+            Operation::LoadImmediate16(register) => {
+                let value = self.read_immediate16(bus);
+                self.set_register16(register, value);
+                false
+            }
+            Operation::StoreSp => {
+                let address = self.read_immediate16(bus);
+                let [low, high] = self.register.sp.to_le_bytes();
+                self.write_byte(bus, address, low);
+                self.write_byte(bus, address.wrapping_add(1), high);
+                false
+            }
+            Operation::LoadSpHl => {
+                self.register.sp = self.register.get_hl();
+                false
+            }
+            Operation::LoadHlSpOffset => {
+                let offset = self.read_immediate(bus);
+                let result = self.add_sp_offset(offset);
+                self.register.set_hl(result);
+                false
+            }
+            Operation::Push(register) => {
+                self.tick(bus);
+                let value = self.register16(register);
+                self.push(bus, value);
+                false
+            }
+            Operation::Pop(register) => {
+                let value = self.pop(bus);
+                self.set_register16(register, value);
+                false
+            }
+            Operation::Alu(operation, operand) => {
+                let value = self.read_operand(bus, operand);
+                self.alu(operation, value);
+                false
+            }
+            Operation::Inc(operand) => {
+                self.modify_operand(bus, operand, |cpu, value| cpu.inc(value));
+                false
+            }
+            Operation::Dec(operand) => {
+                self.modify_operand(bus, operand, |cpu, value| cpu.dec(value));
+                false
+            }
+            Operation::Inc16(register) => {
+                let value = self.register16(register).wrapping_add(1);
+                self.set_register16(register, value);
+                false
+            }
+            Operation::Dec16(register) => {
+                let value = self.register16(register).wrapping_sub(1);
+                self.set_register16(register, value);
+                false
+            }
+            Operation::AddHl(register) => {
+                let value = self.register16(register);
+                self.add_hl(value);
+                false
+            }
+            Operation::AddSp => {
+                let offset = self.read_immediate(bus);
+                self.register.sp = self.add_sp_offset(offset);
+                false
+            }
+            Operation::Daa => {
+                self.daa();
+                false
+            }
+            Operation::Cpl => {
+                self.register.a = !self.register.a;
+                self.register.set_flag(FlagId::N, true);
+                self.register.set_flag(FlagId::H, true);
+                false
+            }
+            Operation::Scf => {
+                self.register.set_flag(FlagId::N, false);
+                self.register.set_flag(FlagId::H, false);
+                self.register.set_flag(FlagId::C, true);
+                false
+            }
+            Operation::Ccf => {
+                let carry = self.register.is_flag_set(FlagId::C);
+                self.register.set_flag(FlagId::N, false);
+                self.register.set_flag(FlagId::H, false);
+                self.register.set_flag(FlagId::C, !carry);
+                false
+            }
+            Operation::RotateA(operation) => {
+                self.register.a = self.shift(operation, self.register.a);
+                self.register.set_flag(FlagId::Z, false);
+                false
+            }
+            Operation::Shift(operation, operand) => {
+                self.modify_operand(bus, operand, |cpu, value| cpu.shift(operation, value));
+                false
+            }
+            Operation::Bit(bit, operand) => {
+                let value = self.read_operand(bus, operand);
+                self.register.set_flag(FlagId::Z, value & (1 << bit) == 0);
+                self.register.set_flag(FlagId::N, false);
+                self.register.set_flag(FlagId::H, true);
+                false
+            }
+            Operation::Res(bit, operand) => {
+                self.modify_operand(bus, operand, |_, value| value & !(1 << bit));
+                false
+            }
+            Operation::Set(bit, operand) => {
+                self.modify_operand(bus, operand, |_, value| value | (1 << bit));
+                false
+            }
+            Operation::Jr(condition) => {
+                let offset = self.read_immediate(bus) as i8;
+                let taken = self.is_condition_met(condition);
+                if taken {
+                    self.register.pc = self.register.pc.wrapping_add_signed(offset as i16);
+                }
+                taken
+            }
+            Operation::Jp(condition) => {
+                let address = self.read_immediate16(bus);
+                let taken = self.is_condition_met(condition);
+                if taken {
+                    self.register.pc = address;
+                }
+                taken
+            }
+            Operation::JpHl => {
+                self.register.pc = self.register.get_hl();
+                false
+            }
+            Operation::Call(condition) => {
+                let address = self.read_immediate16(bus);
+                let taken = self.is_condition_met(condition);
+                if taken {
+                    self.tick(bus);
+                    self.push(bus, self.register.pc);
+                    self.register.pc = address;
+                }
+                taken
+            }
+            Operation::Ret(condition) => {
+                // CONDITIONAL RETURNS TAKE AN EXTRA M-CYCLE TO CHECK THE CONDITION
+                if condition != Condition::Always {
+                    self.tick(bus);
+                }
+                let taken = self.is_condition_met(condition);
+                if taken {
+                    self.register.pc = self.pop(bus);
+                }
+                taken
+            }
+            Operation::Reti => {
+                self.register.pc = self.pop(bus);
+                self.register.set_interrupts_enabled(true, true);
+                false
+            }
+            Operation::Rst(address) => {
+                self.tick(bus);
+                self.push(bus, self.register.pc);
+                self.register.pc = address;
+                false
+            }
+        };
+
+        if taken {
+            instruction.cycles_taken as isize
+        } else {
+            instruction.cycles as isize
+        }
+    }
+
+    fn is_condition_met(&self, condition: Condition) -> bool {
+        match condition {
+            Condition::Always => true,
+            Condition::Nz => !self.register.is_flag_set(FlagId::Z),
+            Condition::Z => self.register.is_flag_set(FlagId::Z),
+            Condition::Nc => !self.register.is_flag_set(FlagId::C),
+            Condition::C => self.register.is_flag_set(FlagId::C),
+        }
+    }
+
+    fn read_immediate<B: Bus>(&mut self, bus: &mut B) -> u8 {
+        let value = self.read_byte(bus, self.register.pc);
+        self.register.pc = self.register.pc.wrapping_add(1);
+        value
+    }
+
+    fn read_immediate16<B: Bus>(&mut self, bus: &mut B) -> u16 {
+        let value = self.read_word(bus, self.register.pc);
+        self.register.pc = self.register.pc.wrapping_add(2);
+        value
+    }
+
+    fn push<B: Bus>(&mut self, bus: &mut B, value: u16) {
+        let [low, high] = value.to_le_bytes();
+        self.register.sp = self.register.sp.wrapping_sub(1);
+        self.write_byte(bus, self.register.sp, high);
+        self.register.sp = self.register.sp.wrapping_sub(1);
+        self.write_byte(bus, self.register.sp, low);
+    }
+
+    fn pop<B: Bus>(&mut self, bus: &mut B) -> u16 {
+        let value = self.read_word(bus, self.register.sp);
+        self.register.sp = self.register.sp.wrapping_add(2);
+        value
+    }
+
+    fn register16(&self, register: Register16) -> u16 {
+        match register {
+            Register16::Af => self.register.get_af(),
+            Register16::Bc => self.register.get_bc(),
+            Register16::De => self.register.get_de(),
+            Register16::Hl => self.register.get_hl(),
+            Register16::Sp => self.register.sp,
+        }
+    }
+
+    fn set_register16(&mut self, register: Register16, value: u16) {
+        match register {
+            Register16::Af => self.register.set_af(value),
+            Register16::Bc => self.register.set_bc(value),
+            Register16::De => self.register.set_de(value),
+            Register16::Hl => self.register.set_hl(value),
+            Register16::Sp => self.register.sp = value,
+        }
+    }
+
+    fn read_operand<B: Bus>(&mut self, bus: &mut B, operand: Operand8) -> u8 {
+        match operand {
+            Operand8::A => self.register.a,
+            Operand8::B => self.register.b,
+            Operand8::C => self.register.c,
+            Operand8::D => self.register.d,
+            Operand8::E => self.register.e,
+            Operand8::H => self.register.h,
+            Operand8::L => self.register.l,
+            Operand8::Immediate => self.read_immediate(bus),
+            _ => {
+                let address = self.operand_address(bus, operand);
+                self.read_byte(bus, address)
+            }
+        }
+    }
+
+    fn write_operand<B: Bus>(&mut self, bus: &mut B, operand: Operand8, value: u8) {
+        match operand {
+            Operand8::A => self.register.a = value,
+            Operand8::B => self.register.b = value,
+            Operand8::C => self.register.c = value,
+            Operand8::D => self.register.d = value,
+            Operand8::E => self.register.e = value,
+            Operand8::H => self.register.h = value,
+            Operand8::L => self.register.l = value,
+            _ => {
+                let address = self.operand_address(bus, operand);
+                self.write_byte(bus, address, value);
+            }
+        }
+    }
+
+    /// Reads the operand, applies the operation and writes the result back. Memory operands are read and written in two M-cycles.
+    fn modify_operand<B: Bus>(
+        &mut self,
+        bus: &mut B,
+        operand: Operand8,
+        operation: impl FnOnce(&mut Self, u8) -> u8,
+    ) {
+        if operand.is_register() {
+            let value = self.read_operand(bus, operand);
+            let result = operation(self, value);
+            self.write_operand(bus, operand, result);
+            return;
+        }
+
+        let address = self.operand_address(bus, operand);
+        let value = self.read_byte(bus, address);
+        let result = operation(self, value);
+        self.write_byte(bus, address, result);
+    }
+
+    /// Returns the address of a memory operand, reading the address bytes of immediate operands.
+    fn operand_address<B: Bus>(&mut self, bus: &mut B, operand: Operand8) -> u16 {
+        match operand {
+            Operand8::IndirectHl => self.register.get_hl(),
+            Operand8::IndirectBc => self.register.get_bc(),
+            Operand8::IndirectDe => self.register.get_de(),
+            Operand8::IndirectHlIncrement => {
+                let address = self.register.get_hl();
+                self.register.set_hl(address.wrapping_add(1));
+                address
+            }
+            Operand8::IndirectHlDecrement => {
+                let address = self.register.get_hl();
+                self.register.set_hl(address.wrapping_sub(1));
+                address
+            }
+            Operand8::IndirectImmediate => self.read_immediate16(bus),
+            Operand8::HighImmediate => 0xFF00 | self.read_immediate(bus) as u16,
+            Operand8::HighC => 0xFF00 | self.register.c as u16,
+            _ => unreachable!("{operand:?} is not a memory operand"),
+        }
+    }
+}
@@ -0,0 +1,180 @@
+/// An 8 bit register or memory location an instruction reads or writes
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Operand8 {
+    A,
+    B,
+    C,
+    D,
+    E,
+    H,
+    L,
+    /// (HL)
+    IndirectHl,
+    /// (BC)
+    IndirectBc,
+    /// (DE)
+    IndirectDe,
+    /// (HL+), increments HL after the access
+    IndirectHlIncrement,
+    /// (HL-), decrements HL after the access
+    IndirectHlDecrement,
+    /// d8
+    Immediate,
+    /// (a16)
+    IndirectImmediate,
+    /// (a8), an address in the 0xFF00 page
+    HighImmediate,
+    /// (C), an address in the 0xFF00 page
+    HighC,
+}
+
+impl Operand8 {
+    pub fn is_register(&self) -> bool {
+        matches!(
+            self,
+            Operand8::A
+                | Operand8::B
+                | Operand8::C
+                | Operand8::D
+                | Operand8::E
+                | Operand8::H
+                | Operand8::L
+        )
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Register16 {
+    Af,
+    Bc,
+    De,
+    Hl,
+    Sp,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Condition {
+    Always,
+    Nz,
+    Z,
+    Nc,
+    C,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum AluOp {
+    Add,
+    Adc,
+    Sub,
+    Sbc,
+    And,
+    Xor,
+    Or,
+    Cp,
+}
+
+/// Rotate and shift operations of the CB prefixed instructions
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum ShiftOp {
+    Rlc,
+    Rrc,
+    Rl,
+    Rr,
+    Sla,
+    Sra,
+    Swap,
+    Srl,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Operation {
+    Nop,
+    Stop,
+    Halt,
+    Di,
+    Ei,
+    /// Locks up the CPU
+    Illegal,
+    /// Selects the CB instruction table for the next byte
+    Prefix,
+    /// Copies the second operand to the first one
+    Load(Operand8, Operand8),
+    LoadImmediate16(Register16),
+    /// LD (a16),SP
+    StoreSp,
+    /// LD SP,HL
+    LoadSpHl,
+    /// LD HL,SP+r8
+    LoadHlSpOffset,
+    Push(Register16),
+    Pop(Register16),
+    Alu(AluOp, Operand8),
+    Inc(Operand8),
+    Dec(Operand8),
+    Inc16(Register16),
+    Dec16(Register16),
+    AddHl(Register16),
+    /// ADD SP,r8
+    AddSp,
+    Daa,
+    Cpl,
+    Scf,
+    Ccf,
+    /// RLCA, RRCA, RLA and RRA, which unlike their CB counterparts always clear the zero flag
+    RotateA(ShiftOp),
+    Shift(ShiftOp, Operand8),
+    Bit(u8, Operand8),
+    Res(u8, Operand8),
+    Set(u8, Operand8),
+    Jr(Condition),
+    Jp(Condition),
+    JpHl,
+    Call(Condition),
+    Ret(Condition),
+    Reti,
+    Rst(u16),
+}
+
+/// An entry of the instruction tables
+///
+/// The mnemonic names the operands that follow the opcode like the usual opcode tables do: d8 and d16 are immediate values, a8 and
+/// a16 addresses and r8 a signed offset.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Instruction {
+    pub mnemonic: &'static str,
+    /// Length in bytes, including the opcode and the CB prefix
+    pub length: u8,
+    /// T-cycles, for conditional jumps the ones when the condition doesn't hold
+    pub cycles: u8,
+    /// T-cycles of a conditional jump that is taken
+    pub cycles_taken: u8,
+    pub operation: Operation,
+}
+
+impl Instruction {
+    pub const fn new(mnemonic: &'static str, length: u8, cycles: u8, operation: Operation) -> Self {
+        Self {
+            mnemonic,
+            length,
+            cycles,
+            cycles_taken: cycles,
+            operation,
+        }
+    }
+
+    pub const fn branch(
+        mnemonic: &'static str,
+        length: u8,
+        cycles: u8,
+        cycles_taken: u8,
+        operation: Operation,
+    ) -> Self {
+        Self {
+            mnemonic,
+            length,
+            cycles,
+            cycles_taken,
+            operation,
+        }
+    }
+}
@@ -0,0 +1,875 @@
+use crate::gameboy::cpu::instructions::instruction::{
+    AluOp, Condition, Instruction, Operand8, Operation, Register16, ShiftOp,
+};
+
+/// All instructions, indexed by their opcode
+pub static INSTRUCTIONS: [Instruction; 256] = [
+    // 0x00
+    Instruction::new("NOP", 1, 4, Operation::Nop),
+    Instruction::new(
+        "LD BC,d16",
+        3,
+        12,
+        Operation::LoadImmediate16(Register16::Bc),
+    ),
+    Instruction::new(
+        "LD (BC),A",
+        1,
+        8,
+        Operation::Load(Operand8::IndirectBc, Operand8::A),
+    ),
+    Instruction::new("INC BC", 1, 8, Operation::Inc16(Register16::Bc)),
+    Instruction::new("INC B", 1, 4, Operation::Inc(Operand8::B)),
+    Instruction::new("DEC B", 1, 4, Operation::Dec(Operand8::B)),
+    Instruction::new(
+        "LD B,d8",
+        2,
+        8,
+        Operation::Load(Operand8::B, Operand8::Immediate),
+    ),
+    Instruction::new("RLCA", 1, 4, Operation::RotateA(ShiftOp::Rlc)),
+    Instruction::new("LD (a16),SP", 3, 20, Operation::StoreSp),
+    Instruction::new("ADD HL,BC", 1, 8, Operation::AddHl(Register16::Bc)),
+    Instruction::new(
+        "LD A,(BC)",
+        1,
+        8,
+        Operation::Load(Operand8::A, Operand8::IndirectBc),
+    ),
+    Instruction::new("DEC BC", 1, 8, Operation::Dec16(Register16::Bc)),
+    Instruction::new("INC C", 1, 4, Operation::Inc(Operand8::C)),
+    Instruction::new("DEC C", 1, 4, Operation::Dec(Operand8::C)),
+    Instruction::new(
+        "LD C,d8",
+        2,
+        8,
+        Operation::Load(Operand8::C, Operand8::Immediate),
+    ),
+    Instruction::new("RRCA", 1, 4, Operation::RotateA(ShiftOp::Rrc)),
+    // 0x10
+    Instruction::new("STOP", 2, 4, Operation::Stop),
+    Instruction::new(
+        "LD DE,d16",
+        3,
+        12,
+        Operation::LoadImmediate16(Register16::De),
+    ),
+    Instruction::new(
+        "LD (DE),A",
+        1,
+        8,
+        Operation::Load(Operand8::IndirectDe, Operand8::A),
+    ),
+    Instruction::new("INC DE", 1, 8, Operation::Inc16(Register16::De)),
+    Instruction::new("INC D", 1, 4, Operation::Inc(Operand8::D)),
+    Instruction::new("DEC D", 1, 4, Operation::Dec(Operand8::D)),
+    Instruction::new(
+        "LD D,d8",
+        2,
+        8,
+        Operation::Load(Operand8::D, Operand8::Immediate),
+    ),
+    Instruction::new("RLA", 1, 4, Operation::RotateA(ShiftOp::Rl)),
+    Instruction::branch("JR r8", 2, 12, 12, Operation::Jr(Condition::Always)),
+    Instruction::new("ADD HL,DE", 1, 8, Operation::AddHl(Register16::De)),
+    Instruction::new(
+        "LD A,(DE)",
+        1,
+        8,
+        Operation::Load(Operand8::A, Operand8::IndirectDe),
+    ),
+    Instruction::new("DEC DE", 1, 8, Operation::Dec16(Register16::De)),
+    Instruction::new("INC E", 1, 4, Operation::Inc(Operand8::E)),
+    Instruction::new("DEC E", 1, 4, Operation::Dec(Operand8::E)),
+    Instruction::new(
+        "LD E,d8",
+        2,
+        8,
+        Operation::Load(Operand8::E, Operand8::Immediate),
+    ),
+    Instruction::new("RRA", 1, 4, Operation::RotateA(ShiftOp::Rr)),
+    // 0x20
+    Instruction::branch("JR NZ,r8", 2, 8, 12, Operation::Jr(Condition::Nz)),
+    Instruction::new(
+        "LD HL,d16",
+        3,
+        12,
+        Operation::LoadImmediate16(Register16::Hl),
+    ),
+    Instruction::new(
+        "LD (HL+),A",
+        1,
+        8,
+        Operation::Load(Operand8::IndirectHlIncrement, Operand8::A),
+    ),
+    Instruction::new("INC HL", 1, 8, Operation::Inc16(Register16::Hl)),
+    Instruction::new("INC H", 1, 4, Operation::Inc(Operand8::H)),
+    Instruction::new("DEC H", 1, 4, Operation::Dec(Operand8::H)),
+    Instruction::new(
+        "LD H,d8",
+        2,
+        8,
+        Operation::Load(Operand8::H, Operand8::Immediate),
+    ),
+    Instruction::new("DAA", 1, 4, Operation::Daa),
+    Instruction::branch("JR Z,r8", 2, 8, 12, Operation::Jr(Condition::Z)),
+    Instruction::new("ADD HL,HL", 1, 8, Operation::AddHl(Register16::Hl)),
+    Instruction::new(
+        "LD A,(HL+)",
+        1,
+        8,
+        Operation::Load(Operand8::A, Operand8::IndirectHlIncrement),
+    ),
+    Instruction::new("DEC HL", 1, 8, Operation::Dec16(Register16::Hl)),
+    Instruction::new("INC L", 1, 4, Operation::Inc(Operand8::L)),
+    Instruction::new("DEC L", 1, 4, Operation::Dec(Operand8::L)),
+    Instruction::new(
+        "LD L,d8",
+        2,
+        8,
+        Operation::Load(Operand8::L, Operand8::Immediate),
+    ),
+    Instruction::new("CPL", 1, 4, Operation::Cpl),
+    // 0x30
+    Instruction::branch("JR NC,r8", 2, 8, 12, Operation::Jr(Condition::Nc)),
+    Instruction::new(
+        "LD SP,d16",
+        3,
+        12,
+        Operation::LoadImmediate16(Register16::Sp),
+    ),
+    Instruction::new(
+        "LD (HL-),A",
+        1,
+        8,
+        Operation::Load(Operand8::IndirectHlDecrement, Operand8::A),
+    ),
+    Instruction::new("INC SP", 1, 8, Operation::Inc16(Register16::Sp)),
+    Instruction::new("INC (HL)", 1, 12, Operation::Inc(Operand8::IndirectHl)),
+    Instruction::new("DEC (HL)", 1, 12, Operation::Dec(Operand8::IndirectHl)),
+    Instruction::new(
+        "LD (HL),d8",
+        2,
+        12,
+        Operation::Load(Operand8::IndirectHl, Operand8::Immediate),
+    ),
+    Instruction::new("SCF", 1, 4, Operation::Scf),
+    Instruction::branch("JR C,r8", 2, 8, 12, Operation::Jr(Condition::C)),
+    Instruction::new("ADD HL,SP", 1, 8, Operation::AddHl(Register16::Sp)),
+    Instruction::new(
+        "LD A,(HL-)",
+        1,
+        8,
+        Operation::Load(Operand8::A, Operand8::IndirectHlDecrement),
+    ),
+    Instruction::new("DEC SP", 1, 8, Operation::Dec16(Register16::Sp)),
+    Instruction::new("INC A", 1, 4, Operation::Inc(Operand8::A)),
+    Instruction::new("DEC A", 1, 4, Operation::Dec(Operand8::A)),
+    Instruction::new(
+        "LD A,d8",
+        2,
+        8,
+        Operation::Load(Operand8::A, Operand8::Immediate),
+    ),
+    Instruction::new("CCF", 1, 4, Operation::Ccf),
+    // 0x40
+    Instruction::new("LD B,B", 1, 4, Operation::Load(Operand8::B, Operand8::B)),
+    Instruction::new("LD B,C", 1, 4, Operation::Load(Operand8::B, Operand8::C)),
+    Instruction::new("LD B,D", 1, 4, Operation::Load(Operand8::B, Operand8::D)),
+    Instruction::new("LD B,E", 1, 4, Operation::Load(Operand8::B, Operand8::E)),
+    Instruction::new("LD B,H", 1, 4, Operation::Load(Operand8::B, Operand8::H)),
+    Instruction::new("LD B,L", 1, 4, Operation::Load(Operand8::B, Operand8::L)),
+    Instruction::new(
+        "LD B,(HL)",
+        1,
+        8,
+        Operation::Load(Operand8::B, Operand8::IndirectHl),
+    ),
+    Instruction::new("LD B,A", 1, 4, Operation::Load(Operand8::B, Operand8::A)),
+    Instruction::new("LD C,B", 1, 4, Operation::Load(Operand8::C, Operand8::B)),
+    Instruction::new("LD C,C", 1, 4, Operation::Load(Operand8::C, Operand8::C)),
+    Instruction::new("LD C,D", 1, 4, Operation::Load(Operand8::C, Operand8::D)),
+    Instruction::new("LD C,E", 1, 4, Operation::Load(Operand8::C, Operand8::E)),
+    Instruction::new("LD C,H", 1, 4, Operation::Load(Operand8::C, Operand8::H)),
+    Instruction::new("LD C,L", 1, 4, Operation::Load(Operand8::C, Operand8::L)),
+    Instruction::new(
+        "LD C,(HL)",
+        1,
+        8,
+        Operation::Load(Operand8::C, Operand8::IndirectHl),
+    ),
+    Instruction::new("LD C,A", 1, 4, Operation::Load(Operand8::C, Operand8::A)),
+    // 0x50
+    Instruction::new("LD D,B", 1, 4, Operation::Load(Operand8::D, Operand8::B)),
+    Instruction::new("LD D,C", 1, 4, Operation::Load(Operand8::D, Operand8::C)),
+    Instruction::new("LD D,D", 1, 4, Operation::Load(Operand8::D, Operand8::D)),
+    Instruction::new("LD D,E", 1, 4, Operation::Load(Operand8::D, Operand8::E)),
+    Instruction::new("LD D,H", 1, 4, Operation::Load(Operand8::D, Operand8::H)),
+    Instruction::new("LD D,L", 1, 4, Operation::Load(Operand8::D, Operand8::L)),
+    Instruction::new(
+        "LD D,(HL)",
+        1,
+        8,
+        Operation::Load(Operand8::D, Operand8::IndirectHl),
+    ),
+    Instruction::new("LD D,A", 1, 4, Operation::Load(Operand8::D, Operand8::A)),
+    Instruction::new("LD E,B", 1, 4, Operation::Load(Operand8::E, Operand8::B)),
+    Instruction::new("LD E,C", 1, 4, Operation::Load(Operand8::E, Operand8::C)),
+    Instruction::new("LD E,D", 1, 4, Operation::Load(Operand8::E, Operand8::D)),
+    Instruction::new("LD E,E", 1, 4, Operation::Load(Operand8::E, Operand8::E)),
+    Instruction::new("LD E,H", 1, 4, Operation::Load(Operand8::E, Operand8::H)),
+    Instruction::new("LD E,L", 1, 4, Operation::Load(Operand8::E, Operand8::L)),
+    Instruction::new(
+        "LD E,(HL)",
+        1,
+        8,
+        Operation::Load(Operand8::E, Operand8::IndirectHl),
+    ),
+    Instruction::new("LD E,A", 1, 4, Operation::Load(Operand8::E, Operand8::A)),
+    // 0x60
+    Instruction::new("LD H,B", 1, 4, Operation::Load(Operand8::H, Operand8::B)),
+    Instruction::new("LD H,C", 1, 4, Operation::Load(Operand8::H, Operand8::C)),
+    Instruction::new("LD H,D", 1, 4, Operation::Load(Operand8::H, Operand8::D)),
+    Instruction::new("LD H,E", 1, 4, Operation::Load(Operand8::H, Operand8::E)),
+    Instruction::new("LD H,H", 1, 4, Operation::Load(Operand8::H, Operand8::H)),
+    Instruction::new("LD H,L", 1, 4, Operation::Load(Operand8::H, Operand8::L)),
+    Instruction::new(
+        "LD H,(HL)",
+        1,
+        8,
+        Operation::Load(Operand8::H, Operand8::IndirectHl),
+    ),
+    Instruction::new("LD H,A", 1, 4, Operation::Load(Operand8::H, Operand8::A)),
+    Instruction::new("LD L,B", 1, 4, Operation::Load(Operand8::L, Operand8::B)),
+    Instruction::new("LD L,C", 1, 4, Operation::Load(Operand8::L, Operand8::C)),
+    Instruction::new("LD L,D", 1, 4, Operation::Load(Operand8::L, Operand8::D)),
+    Instruction::new("LD L,E", 1, 4, Operation::Load(Operand8::L, Operand8::E)),
+    Instruction::new("LD L,H", 1, 4, Operation::Load(Operand8::L, Operand8::H)),
+    Instruction::new("LD L,L", 1, 4, Operation::Load(Operand8::L, Operand8::L)),
+    Instruction::new(
+        "LD L,(HL)",
+        1,
+        8,
+        Operation::Load(Operand8::L, Operand8::IndirectHl),
+    ),
+    Instruction::new("LD L,A", 1, 4, Operation::Load(Operand8::L, Operand8::A)),
+    // 0x70
+    Instruction::new(
+        "LD (HL),B",
+        1,
+        8,
+        Operation::Load(Operand8::IndirectHl, Operand8::B),
+    ),
+    Instruction::new(
+        "LD (HL),C",
+        1,
+        8,
+        Operation::Load(Operand8::IndirectHl, Operand8::C),
+    ),
+    Instruction::new(
+        "LD (HL),D",
+        1,
+        8,
+        Operation::Load(Operand8::IndirectHl, Operand8::D),
+    ),
+    Instruction::new(
+        "LD (HL),E",
+        1,
+        8,
+        Operation::Load(Operand8::IndirectHl, Operand8::E),
+    ),
+    Instruction::new(
+        "LD (HL),H",
+        1,
+        8,
+        Operation::Load(Operand8::IndirectHl, Operand8::H),
+    ),
+    Instruction::new(
+        "LD (HL),L",
+        1,
+        8,
+        Operation::Load(Operand8::IndirectHl, Operand8::L),
+    ),
+    Instruction::new("HALT", 1, 4, Operation::Halt),
+    Instruction::new(
+        "LD (HL),A",
+        1,
+        8,
+        Operation::Load(Operand8::IndirectHl, Operand8::A),
+    ),
+    Instruction::new("LD A,B", 1, 4, Operation::Load(Operand8::A, Operand8::B)),
+    Instruction::new("LD A,C", 1, 4, Operation::Load(Operand8::A, Operand8::C)),
+    Instruction::new("LD A,D", 1, 4, Operation::Load(Operand8::A, Operand8::D)),
+    Instruction::new("LD A,E", 1, 4, Operation::Load(Operand8::A, Operand8::E)),
+    Instruction::new("LD A,H", 1, 4, Operation::Load(Operand8::A, Operand8::H)),
+    Instruction::new("LD A,L", 1, 4, Operation::Load(Operand8::A, Operand8::L)),
+    Instruction::new(
+        "LD A,(HL)",
+        1,
+        8,
+        Operation::Load(Operand8::A, Operand8::IndirectHl),
+    ),
+    Instruction::new("LD A,A", 1, 4, Operation::Load(Operand8::A, Operand8::A)),
+    // 0x80
+    Instruction::new("ADD A,B", 1, 4, Operation::Alu(AluOp::Add, Operand8::B)),
+    Instruction::new("ADD A,C", 1, 4, Operation::Alu(AluOp::Add, Operand8::C)),
+    Instruction::new("ADD A,D", 1, 4, Operation::Alu(AluOp::Add, Operand8::D)),
+    Instruction::new("ADD A,E", 1, 4, Operation::Alu(AluOp::Add, Operand8::E)),
+    Instruction::new("ADD A,H", 1, 4, Operation::Alu(AluOp::Add, Operand8::H)),
+    Instruction::new("ADD A,L", 1, 4, Operation::Alu(AluOp::Add, Operand8::L)),
+    Instruction::new(
+        "ADD A,(HL)",
+        1,
+        8,
+        Operation::Alu(AluOp::Add, Operand8::IndirectHl),
+    ),
+    Instruction::new("ADD A,A", 1, 4, Operation::Alu(AluOp::Add, Operand8::A)),
+    Instruction::new("ADC A,B", 1, 4, Operation::Alu(AluOp::Adc, Operand8::B)),
+    Instruction::new("ADC A,C", 1, 4, Operation::Alu(AluOp::Adc, Operand8::C)),
+    Instruction::new("ADC A,D", 1, 4, Operation::Alu(AluOp::Adc, Operand8::D)),
+    Instruction::new("ADC A,E", 1, 4, Operation::Alu(AluOp::Adc, Operand8::E)),
+    Instruction::new("ADC A,H", 1, 4, Operation::Alu(AluOp::Adc, Operand8::H)),
+    Instruction::new("ADC A,L", 1, 4, Operation::Alu(AluOp::Adc, Operand8::L)),
+    Instruction::new(
+        "ADC A,(HL)",
+        1,
+        8,
+        Operation::Alu(AluOp::Adc, Operand8::IndirectHl),
+    ),
+    Instruction::new("ADC A,A", 1, 4, Operation::Alu(AluOp::Adc, Operand8::A)),
+    // 0x90
+    Instruction::new("SUB B", 1, 4, Operation::Alu(AluOp::Sub, Operand8::B)),
+    Instruction::new("SUB C", 1, 4, Operation::Alu(AluOp::Sub, Operand8::C)),
+    Instruction::new("SUB D", 1, 4, Operation::Alu(AluOp::Sub, Operand8::D)),
+    Instruction::new("SUB E", 1, 4, Operation::Alu(AluOp::Sub, Operand8::E)),
+    Instruction::new("SUB H", 1, 4, Operation::Alu(AluOp::Sub, Operand8::H)),
+    Instruction::new("SUB L", 1, 4, Operation::Alu(AluOp::Sub, Operand8::L)),
+    Instruction::new(
+        "SUB (HL)",
+        1,
+        8,
+        Operation::Alu(AluOp::Sub, Operand8::IndirectHl),
+    ),
+    Instruction::new("SUB A", 1, 4, Operation::Alu(AluOp::Sub, Operand8::A)),
+    Instruction::new("SBC A,B", 1, 4, Operation::Alu(AluOp::Sbc, Operand8::B)),
+    Instruction::new("SBC A,C", 1, 4, Operation::Alu(AluOp::Sbc, Operand8::C)),
+    Instruction::new("SBC A,D", 1, 4, Operation::Alu(AluOp::Sbc, Operand8::D)),
+    Instruction::new("SBC A,E", 1, 4, Operation::Alu(AluOp::Sbc, Operand8::E)),
+    Instruction::new("SBC A,H", 1, 4, Operation::Alu(AluOp::Sbc, Operand8::H)),
+    Instruction::new("SBC A,L", 1, 4, Operation::Alu(AluOp::Sbc, Operand8::L)),
+    Instruction::new(
+        "SBC A,(HL)",
+        1,
+        8,
+        Operation::Alu(AluOp::Sbc, Operand8::IndirectHl),
+    ),
+    Instruction::new("SBC A,A", 1, 4, Operation::Alu(AluOp::Sbc, Operand8::A)),
+    // 0xA0
+    Instruction::new("AND B", 1, 4, Operation::Alu(AluOp::And, Operand8::B)),
+    Instruction::new("AND C", 1, 4, Operation::Alu(AluOp::And, Operand8::C)),
+    Instruction::new("AND D", 1, 4, Operation::Alu(AluOp::And, Operand8::D)),
+    Instruction::new("AND E", 1, 4, Operation::Alu(AluOp::And, Operand8::E)),
+    Instruction::new("AND H", 1, 4, Operation::Alu(AluOp::And, Operand8::H)),
+    Instruction::new("AND L", 1, 4, Operation::Alu(AluOp::And, Operand8::L)),
+    Instruction::new(
+        "AND (HL)",
+        1,
+        8,
+        Operation::Alu(AluOp::And, Operand8::IndirectHl),
+    ),
+    Instruction::new("AND A", 1, 4, Operation::Alu(AluOp::And, Operand8::A)),
+    Instruction::new("XOR B", 1, 4, Operation::Alu(AluOp::Xor, Operand8::B)),
+    Instruction::new("XOR C", 1, 4, Operation::Alu(AluOp::Xor, Operand8::C)),
+    Instruction::new("XOR D", 1, 4, Operation::Alu(AluOp::Xor, Operand8::D)),
+    Instruction::new("XOR E", 1, 4, Operation::Alu(AluOp::Xor, Operand8::E)),
+    Instruction::new("XOR H", 1, 4, Operation::Alu(AluOp::Xor, Operand8::H)),
+    Instruction::new("XOR L", 1, 4, Operation::Alu(AluOp::Xor, Operand8::L)),
+    Instruction::new(
+        "XOR (HL)",
+        1,
+        8,
+        Operation::Alu(AluOp::Xor, Operand8::IndirectHl),
+    ),
+    Instruction::new("XOR A", 1, 4, Operation::Alu(AluOp::Xor, Operand8::A)),
+    // 0xB0
+    Instruction::new("OR B", 1, 4, Operation::Alu(AluOp::Or, Operand8::B)),
+    Instruction::new("OR C", 1, 4, Operation::Alu(AluOp::Or, Operand8::C)),
+    Instruction::new("OR D", 1, 4, Operation::Alu(AluOp::Or, Operand8::D)),
+    Instruction::new("OR E", 1, 4, Operation::Alu(AluOp::Or, Operand8::E)),
+    Instruction::new("OR H", 1, 4, Operation::Alu(AluOp::Or, Operand8::H)),
+    Instruction::new("OR L", 1, 4, Operation::Alu(AluOp::Or, Operand8::L)),
+    Instruction::new(
+        "OR (HL)",
+        1,
+        8,
+        Operation::Alu(AluOp::Or, Operand8::IndirectHl),
+    ),
+    Instruction::new("OR A", 1, 4, Operation::Alu(AluOp::Or, Operand8::A)),
+    Instruction::new("CP B", 1, 4, Operation::Alu(AluOp::Cp, Operand8::B)),
+    Instruction::new("CP C", 1, 4, Operation::Alu(AluOp::Cp, Operand8::C)),
+    Instruction::new("CP D", 1, 4, Operation::Alu(AluOp::Cp, Operand8::D)),
+    Instruction::new("CP E", 1, 4, Operation::Alu(AluOp::Cp, Operand8::E)),
+    Instruction::new("CP H", 1, 4, Operation::Alu(AluOp::Cp, Operand8::H)),
+    Instruction::new("CP L", 1, 4, Operation::Alu(AluOp::Cp, Operand8::L)),
+    Instruction::new(
+        "CP (HL)",
+        1,
+        8,
+        Operation::Alu(AluOp::Cp, Operand8::IndirectHl),
+    ),
+    Instruction::new("CP A", 1, 4, Operation::Alu(AluOp::Cp, Operand8::A)),
+    // 0xC0
+    Instruction::branch("RET NZ", 1, 8, 20, Operation::Ret(Condition::Nz)),
+    Instruction::new("POP BC", 1, 12, Operation::Pop(Register16::Bc)),
+    Instruction::branch("JP NZ,a16", 3, 12, 16, Operation::Jp(Condition::Nz)),
+    Instruction::branch("JP a16", 3, 16, 16, Operation::Jp(Condition::Always)),
+    Instruction::branch("CALL NZ,a16", 3, 12, 24, Operation::Call(Condition::Nz)),
+    Instruction::new("PUSH BC", 1, 16, Operation::Push(Register16::Bc)),
+    Instruction::new(
+        "ADD A,d8",
+        2,
+        8,
+        Operation::Alu(AluOp::Add, Operand8::Immediate),
+    ),
+    Instruction::new("RST $00", 1, 16, Operation::Rst(0x00)),
+    Instruction::branch("RET Z", 1, 8, 20, Operation::Ret(Condition::Z)),
+    Instruction::new("RET", 1, 16, Operation::Ret(Condition::Always)),
+    Instruction::branch("JP Z,a16", 3, 12, 16, Operation::Jp(Condition::Z)),
+    Instruction::new("PREFIX CB", 1, 4, Operation::Prefix),
+    Instruction::branch("CALL Z,a16", 3, 12, 24, Operation::Call(Condition::Z)),
+    Instruction::branch("CALL a16", 3, 24, 24, Operation::Call(Condition::Always)),
+    Instruction::new(
+        "ADC A,d8",
+        2,
+        8,
+        Operation::Alu(AluOp::Adc, Operand8::Immediate),
+    ),
+    Instruction::new("RST $08", 1, 16, Operation::Rst(0x08)),
+    // 0xD0
+    Instruction::branch("RET NC", 1, 8, 20, Operation::Ret(Condition::Nc)),
+    Instruction::new("POP DE", 1, 12, Operation::Pop(Register16::De)),
+    Instruction::branch("JP NC,a16", 3, 12, 16, Operation::Jp(Condition::Nc)),
+    Instruction::new("ILLEGAL", 1, 4, Operation::Illegal),
+    Instruction::branch("CALL NC,a16", 3, 12, 24, Operation::Call(Condition::Nc)),
+    Instruction::new("PUSH DE", 1, 16, Operation::Push(Register16::De)),
+    Instruction::new(
+        "SUB d8",
+        2,
+        8,
+        Operation::Alu(AluOp::Sub, Operand8::Immediate),
+    ),
+    Instruction::new("RST $10", 1, 16, Operation::Rst(0x10)),
+    Instruction::branch("RET C", 1, 8, 20, Operation::Ret(Condition::C)),
+    Instruction::new("RETI", 1, 16, Operation::Reti),
+    Instruction::branch("JP C,a16", 3, 12, 16, Operation::Jp(Condition::C)),
+    Instruction::new("ILLEGAL", 1, 4, Operation::Illegal),
+    Instruction::branch("CALL C,a16", 3, 12, 24, Operation::Call(Condition::C)),
+    Instruction::new("ILLEGAL", 1, 4, Operation::Illegal),
+    Instruction::new(
+        "SBC A,d8",
+        2,
+        8,
+        Operation::Alu(AluOp::Sbc, Operand8::Immediate),
+    ),
+    Instruction::new("RST $18", 1, 16, Operation::Rst(0x18)),
+    // 0xE0
+    Instruction::new(
+        "LDH (a8),A",
+        2,
+        12,
+        Operation::Load(Operand8::HighImmediate, Operand8::A),
+    ),
+    Instruction::new("POP HL", 1, 12, Operation::Pop(Register16::Hl)),
+    Instruction::new(
+        "LD (C),A",
+        1,
+        8,
+        Operation::Load(Operand8::HighC, Operand8::A),
+    ),
+    Instruction::new("ILLEGAL", 1, 4, Operation::Illegal),
+    Instruction::new("ILLEGAL", 1, 4, Operation::Illegal),
+    Instruction::new("PUSH HL", 1, 16, Operation::Push(Register16::Hl)),
+    Instruction::new(
+        "AND d8",
+        2,
+        8,
+        Operation::Alu(AluOp::And, Operand8::Immediate),
+    ),
+    Instruction::new("RST $20", 1, 16, Operation::Rst(0x20)),
+    Instruction::new("ADD SP,r8", 2, 16, Operation::AddSp),
+    Instruction::new("JP HL", 1, 4, Operation::JpHl),
+    Instruction::new(
+        "LD (a16),A",
+        3,
+        16,
+        Operation::Load(Operand8::IndirectImmediate, Operand8::A),
+    ),
+    Instruction::new("ILLEGAL", 1, 4, Operation::Illegal),
+    Instruction::new("ILLEGAL", 1, 4, Operation::Illegal),
+    Instruction::new("ILLEGAL", 1, 4, Operation::Illegal),
+    Instruction::new(
+        "XOR d8",
+        2,
+        8,
+        Operation::Alu(AluOp::Xor, Operand8::Immediate),
+    ),
+    Instruction::new("RST $28", 1, 16, Operation::Rst(0x28)),
+    // 0xF0
+    Instruction::new(
+        "LDH A,(a8)",
+        2,
+        12,
+        Operation::Load(Operand8::A, Operand8::HighImmediate),
+    ),
+    Instruction::new("POP AF", 1, 12, Operation::Pop(Register16::Af)),
+    Instruction::new(
+        "LD A,(C)",
+        1,
+        8,
+        Operation::Load(Operand8::A, Operand8::HighC),
+    ),
+    Instruction::new("DI", 1, 4, Operation::Di),
+    Instruction::new("ILLEGAL", 1, 4, Operation::Illegal),
+    Instruction::new("PUSH AF", 1, 16, Operation::Push(Register16::Af)),
+    Instruction::new(
+        "OR d8",
+        2,
+        8,
+        Operation::Alu(AluOp::Or, Operand8::Immediate),
+    ),
+    Instruction::new("RST $30", 1, 16, Operation::Rst(0x30)),
+    Instruction::new("LD HL,SP+r8", 2, 12, Operation::LoadHlSpOffset),
+    Instruction::new("LD SP,HL", 1, 8, Operation::LoadSpHl),
+    Instruction::new(
+        "LD A,(a16)",
+        3,
+        16,
+        Operation::Load(Operand8::A, Operand8::IndirectImmediate),
+    ),
+    Instruction::new("EI", 1, 4, Operation::Ei),
+    Instruction::new("ILLEGAL", 1, 4, Operation::Illegal),
+    Instruction::new("ILLEGAL", 1, 4, Operation::Illegal),
+    Instruction::new(
+        "CP d8",
+        2,
+        8,
+        Operation::Alu(AluOp::Cp, Operand8::Immediate),
+    ),
+    Instruction::new("RST $38", 1, 16, Operation::Rst(0x38)),
+];
+
+/// The instructions following the CB prefix, indexed by the second byte
+pub static CB_INSTRUCTIONS: [Instruction; 256] = [
+    // 0x00
+    Instruction::new("RLC B", 2, 8, Operation::Shift(ShiftOp::Rlc, Operand8::B)),
+    Instruction::new("RLC C", 2, 8, Operation::Shift(ShiftOp::Rlc, Operand8::C)),
+    Instruction::new("RLC D", 2, 8, Operation::Shift(ShiftOp::Rlc, Operand8::D)),
+    Instruction::new("RLC E", 2, 8, Operation::Shift(ShiftOp::Rlc, Operand8::E)),
+    Instruction::new("RLC H", 2, 8, Operation::Shift(ShiftOp::Rlc, Operand8::H)),
+    Instruction::new("RLC L", 2, 8, Operation::Shift(ShiftOp::Rlc, Operand8::L)),
+    Instruction::new(
+        "RLC (HL)",
+        2,
+        16,
+        Operation::Shift(ShiftOp::Rlc, Operand8::IndirectHl),
+    ),
+    Instruction::new("RLC A", 2, 8, Operation::Shift(ShiftOp::Rlc, Operand8::A)),
+    Instruction::new("RRC B", 2, 8, Operation::Shift(ShiftOp::Rrc, Operand8::B)),
+    Instruction::new("RRC C", 2, 8, Operation::Shift(ShiftOp::Rrc, Operand8::C)),
+    Instruction::new("RRC D", 2, 8, Operation::Shift(ShiftOp::Rrc, Operand8::D)),
+    Instruction::new("RRC E", 2, 8, Operation::Shift(ShiftOp::Rrc, Operand8::E)),
+    Instruction::new("RRC H", 2, 8, Operation::Shift(ShiftOp::Rrc, Operand8::H)),
+    Instruction::new("RRC L", 2, 8, Operation::Shift(ShiftOp::Rrc, Operand8::L)),
+    Instruction::new(
+        "RRC (HL)",
+        2,
+        16,
+        Operation::Shift(ShiftOp::Rrc, Operand8::IndirectHl),
+    ),
+    Instruction::new("RRC A", 2, 8, Operation::Shift(ShiftOp::Rrc, Operand8::A)),
+    // 0x10
+    Instruction::new("RL B", 2, 8, Operation::Shift(ShiftOp::Rl, Operand8::B)),
+    Instruction::new("RL C", 2, 8, Operation::Shift(ShiftOp::Rl, Operand8::C)),
+    Instruction::new("RL D", 2, 8, Operation::Shift(ShiftOp::Rl, Operand8::D)),
+    Instruction::new("RL E", 2, 8, Operation::Shift(ShiftOp::Rl, Operand8::E)),
+    Instruction::new("RL H", 2, 8, Operation::Shift(ShiftOp::Rl, Operand8::H)),
+    Instruction::new("RL L", 2, 8, Operation::Shift(ShiftOp::Rl, Operand8::L)),
+    Instruction::new(
+        "RL (HL)",
+        2,
+        16,
+        Operation::Shift(ShiftOp::Rl, Operand8::IndirectHl),
+    ),
+    Instruction::new("RL A", 2, 8, Operation::Shift(ShiftOp::Rl, Operand8::A)),
+    Instruction::new("RR B", 2, 8, Operation::Shift(ShiftOp::Rr, Operand8::B)),
+    Instruction::new("RR C", 2, 8, Operation::Shift(ShiftOp::Rr, Operand8::C)),
+    Instruction::new("RR D", 2, 8, Operation::Shift(ShiftOp::Rr, Operand8::D)),
+    Instruction::new("RR E", 2, 8, Operation::Shift(ShiftOp::Rr, Operand8::E)),
+    Instruction::new("RR H", 2, 8, Operation::Shift(ShiftOp::Rr, Operand8::H)),
+    Instruction::new("RR L", 2, 8, Operation::Shift(ShiftOp::Rr, Operand8::L)),
+    Instruction::new(
+        "RR (HL)",
+        2,
+        16,
+        Operation::Shift(ShiftOp::Rr, Operand8::IndirectHl),
+    ),
+    Instruction::new("RR A", 2, 8, Operation::Shift(ShiftOp::Rr, Operand8::A)),
+    // 0x20
+    Instruction::new("SLA B", 2, 8, Operation::Shift(ShiftOp::Sla, Operand8::B)),
+    Instruction::new("SLA C", 2, 8, Operation::Shift(ShiftOp::Sla, Operand8::C)),
+    Instruction::new("SLA D", 2, 8, Operation::Shift(ShiftOp::Sla, Operand8::D)),
+    Instruction::new("SLA E", 2, 8, Operation::Shift(ShiftOp::Sla, Operand8::E)),
+    Instruction::new("SLA H", 2, 8, Operation::Shift(ShiftOp::Sla, Operand8::H)),
+    Instruction::new("SLA L", 2, 8, Operation::Shift(ShiftOp::Sla, Operand8::L)),
+    Instruction::new(
+        "SLA (HL)",
+        2,
+        16,
+        Operation::Shift(ShiftOp::Sla, Operand8::IndirectHl),
+    ),
+    Instruction::new("SLA A", 2, 8, Operation::Shift(ShiftOp::Sla, Operand8::A)),
+    Instruction::new("SRA B", 2, 8, Operation::Shift(ShiftOp::Sra, Operand8::B)),
+    Instruction::new("SRA C", 2, 8, Operation::Shift(ShiftOp::Sra, Operand8::C)),
+    Instruction::new("SRA D", 2, 8, Operation::Shift(ShiftOp::Sra, Operand8::D)),
+    Instruction::new("SRA E", 2, 8, Operation::Shift(ShiftOp::Sra, Operand8::E)),
+    Instruction::new("SRA H", 2, 8, Operation::Shift(ShiftOp::Sra, Operand8::H)),
+    Instruction::new("SRA L", 2, 8, Operation::Shift(ShiftOp::Sra, Operand8::L)),
+    Instruction::new(
+        "SRA (HL)",
+        2,
+        16,
+        Operation::Shift(ShiftOp::Sra, Operand8::IndirectHl),
+    ),
+    Instruction::new("SRA A", 2, 8, Operation::Shift(ShiftOp::Sra, Operand8::A)),
+    // 0x30
+    Instruction::new("SWAP B", 2, 8, Operation::Shift(ShiftOp::Swap, Operand8::B)),
+    Instruction::new("SWAP C", 2, 8, Operation::Shift(ShiftOp::Swap, Operand8::C)),
+    Instruction::new("SWAP D", 2, 8, Operation::Shift(ShiftOp::Swap, Operand8::D)),
+    Instruction::new("SWAP E", 2, 8, Operation::Shift(ShiftOp::Swap, Operand8::E)),
+    Instruction::new("SWAP H", 2, 8, Operation::Shift(ShiftOp::Swap, Operand8::H)),
+    Instruction::new("SWAP L", 2, 8, Operation::Shift(ShiftOp::Swap, Operand8::L)),
+    Instruction::new(
+        "SWAP (HL)",
+        2,
+        16,
+        Operation::Shift(ShiftOp::Swap, Operand8::IndirectHl),
+    ),
+    Instruction::new("SWAP A", 2, 8, Operation::Shift(ShiftOp::Swap, Operand8::A)),
+    Instruction::new("SRL B", 2, 8, Operation::Shift(ShiftOp::Srl, Operand8::B)),
+    Instruction::new("SRL C", 2, 8, Operation::Shift(ShiftOp::Srl, Operand8::C)),
+    Instruction::new("SRL D", 2, 8, Operation::Shift(ShiftOp::Srl, Operand8::D)),
+    Instruction::new("SRL E", 2, 8, Operation::Shift(ShiftOp::Srl, Operand8::E)),
+    Instruction::new("SRL H", 2, 8, Operation::Shift(ShiftOp::Srl, Operand8::H)),
+    Instruction::new("SRL L", 2, 8, Operation::Shift(ShiftOp::Srl, Operand8::L)),
+    Instruction::new(
+        "SRL (HL)",
+        2,
+        16,
+        Operation::Shift(ShiftOp::Srl, Operand8::IndirectHl),
+    ),
+    Instruction::new("SRL A", 2, 8, Operation::Shift(ShiftOp::Srl, Operand8::A)),
+    // 0x40
+    Instruction::new("BIT 0,B", 2, 8, Operation::Bit(0, Operand8::B)),
+    Instruction::new("BIT 0,C", 2, 8, Operation::Bit(0, Operand8::C)),
+    Instruction::new("BIT 0,D", 2, 8, Operation::Bit(0, Operand8::D)),
+    Instruction::new("BIT 0,E", 2, 8, Operation::Bit(0, Operand8::E)),
+    Instruction::new("BIT 0,H", 2, 8, Operation::Bit(0, Operand8::H)),
+    Instruction::new("BIT 0,L", 2, 8, Operation::Bit(0, Operand8::L)),
+    Instruction::new("BIT 0,(HL)", 2, 12, Operation::Bit(0, Operand8::IndirectHl)),
+    Instruction::new("BIT 0,A", 2, 8, Operation::Bit(0, Operand8::A)),
+    Instruction::new("BIT 1,B", 2, 8, Operation::Bit(1, Operand8::B)),
+    Instruction::new("BIT 1,C", 2, 8, Operation::Bit(1, Operand8::C)),
+    Instruction::new("BIT 1,D", 2, 8, Operation::Bit(1, Operand8::D)),
+    Instruction::new("BIT 1,E", 2, 8, Operation::Bit(1, Operand8::E)),
+    Instruction::new("BIT 1,H", 2, 8, Operation::Bit(1, Operand8::H)),
+    Instruction::new("BIT 1,L", 2, 8, Operation::Bit(1, Operand8::L)),
+    Instruction::new("BIT 1,(HL)", 2, 12, Operation::Bit(1, Operand8::IndirectHl)),
+    Instruction::new("BIT 1,A", 2, 8, Operation::Bit(1, Operand8::A)),
+    // 0x50
+    Instruction::new("BIT 2,B", 2, 8, Operation::Bit(2, Operand8::B)),
+    Instruction::new("BIT 2,C", 2, 8, Operation::Bit(2, Operand8::C)),
+    Instruction::new("BIT 2,D", 2, 8, Operation::Bit(2, Operand8::D)),
+    Instruction::new("BIT 2,E", 2, 8, Operation::Bit(2, Operand8::E)),
+    Instruction::new("BIT 2,H", 2, 8, Operation::Bit(2, Operand8::H)),
+    Instruction::new("BIT 2,L", 2, 8, Operation::Bit(2, Operand8::L)),
+    Instruction::new("BIT 2,(HL)", 2, 12, Operation::Bit(2, Operand8::IndirectHl)),
+    Instruction::new("BIT 2,A", 2, 8, Operation::Bit(2, Operand8::A)),
+    Instruction::new("BIT 3,B", 2, 8, Operation::Bit(3, Operand8::B)),
+    Instruction::new("BIT 3,C", 2, 8, Operation::Bit(3, Operand8::C)),
+    Instruction::new("BIT 3,D", 2, 8, Operation::Bit(3, Operand8::D)),
+    Instruction::new("BIT 3,E", 2, 8, Operation::Bit(3, Operand8::E)),
+    Instruction::new("BIT 3,H", 2, 8, Operation::Bit(3, Operand8::H)),
+    Instruction::new("BIT 3,L", 2, 8, Operation::Bit(3, Operand8::L)),
+    Instruction::new("BIT 3,(HL)", 2, 12, Operation::Bit(3, Operand8::IndirectHl)),
+    Instruction::new("BIT 3,A", 2, 8, Operation::Bit(3, Operand8::A)),
+    // 0x60
+    Instruction::new("BIT 4,B", 2, 8, Operation::Bit(4, Operand8::B)),
+    Instruction::new("BIT 4,C", 2, 8, Operation::Bit(4, Operand8::C)),
+    Instruction::new("BIT 4,D", 2, 8, Operation::Bit(4, Operand8::D)),
+    Instruction::new("BIT 4,E", 2, 8, Operation::Bit(4, Operand8::E)),
+    Instruction::new("BIT 4,H", 2, 8, Operation::Bit(4, Operand8::H)),
+    Instruction::new("BIT 4,L", 2, 8, Operation::Bit(4, Operand8::L)),
+    Instruction::new("BIT 4,(HL)", 2, 12, Operation::Bit(4, Operand8::IndirectHl)),
+    Instruction::new("BIT 4,A", 2, 8, Operation::Bit(4, Operand8::A)),
+    Instruction::new("BIT 5,B", 2, 8, Operation::Bit(5, Operand8::B)),
+    Instruction::new("BIT 5,C", 2, 8, Operation::Bit(5, Operand8::C)),
+    Instruction::new("BIT 5,D", 2, 8, Operation::Bit(5, Operand8::D)),
+    Instruction::new("BIT 5,E", 2, 8, Operation::Bit(5, Operand8::E)),
+    Instruction::new("BIT 5,H", 2, 8, Operation::Bit(5, Operand8::H)),
+    Instruction::new("BIT 5,L", 2, 8, Operation::Bit(5, Operand8::L)),
+    Instruction::new("BIT 5,(HL)", 2, 12, Operation::Bit(5, Operand8::IndirectHl)),
+    Instruction::new("BIT 5,A", 2, 8, Operation::Bit(5, Operand8::A)),
+    // 0x70
+    Instruction::new("BIT 6,B", 2, 8, Operation::Bit(6, Operand8::B)),
+    Instruction::new("BIT 6,C", 2, 8, Operation::Bit(6, Operand8::C)),
+    Instruction::new("BIT 6,D", 2, 8, Operation::Bit(6, Operand8::D)),
+    Instruction::new("BIT 6,E", 2, 8, Operation::Bit(6, Operand8::E)),
+    Instruction::new("BIT 6,H", 2, 8, Operation::Bit(6, Operand8::H)),
+    Instruction::new("BIT 6,L", 2, 8, Operation::Bit(6, Operand8::L)),
+    Instruction::new("BIT 6,(HL)", 2, 12, Operation::Bit(6, Operand8::IndirectHl)),
+    Instruction::new("BIT 6,A", 2, 8, Operation::Bit(6, Operand8::A)),
+    Instruction::new("BIT 7,B", 2, 8, Operation::Bit(7, Operand8::B)),
+    Instruction::new("BIT 7,C", 2, 8, Operation::Bit(7, Operand8::C)),
+    Instruction::new("BIT 7,D", 2, 8, Operation::Bit(7, Operand8::D)),
+    Instruction::new("BIT 7,E", 2, 8, Operation::Bit(7, Operand8::E)),
+    Instruction::new("BIT 7,H", 2, 8, Operation::Bit(7, Operand8::H)),
+    Instruction::new("BIT 7,L", 2, 8, Operation::Bit(7, Operand8::L)),
+    Instruction::new("BIT 7,(HL)", 2, 12, Operation::Bit(7, Operand8::IndirectHl)),
+    Instruction::new("BIT 7,A", 2, 8, Operation::Bit(7, Operand8::A)),
+    // 0x80
+    Instruction::new("RES 0,B", 2, 8, Operation::Res(0, Operand8::B)),
+    Instruction::new("RES 0,C", 2, 8, Operation::Res(0, Operand8::C)),
+    Instruction::new("RES 0,D", 2, 8, Operation::Res(0, Operand8::D)),
+    Instruction::new("RES 0,E", 2, 8, Operation::Res(0, Operand8::E)),
+    Instruction::new("RES 0,H", 2, 8, Operation::Res(0, Operand8::H)),
+    Instruction::new("RES 0,L", 2, 8, Operation::Res(0, Operand8::L)),
+    Instruction::new("RES 0,(HL)", 2, 16, Operation::Res(0, Operand8::IndirectHl)),
+    Instruction::new("RES 0,A", 2, 8, Operation::Res(0, Operand8::A)),
+    Instruction::new("RES 1,B", 2, 8, Operation::Res(1, Operand8::B)),
+    Instruction::new("RES 1,C", 2, 8, Operation::Res(1, Operand8::C)),
+    Instruction::new("RES 1,D", 2, 8, Operation::Res(1, Operand8::D)),
+    Instruction::new("RES 1,E", 2, 8, Operation::Res(1, Operand8::E)),
+    Instruction::new("RES 1,H", 2, 8, Operation::Res(1, Operand8::H)),
+    Instruction::new("RES 1,L", 2, 8, Operation::Res(1, Operand8::L)),
+    Instruction::new("RES 1,(HL)", 2, 16, Operation::Res(1, Operand8::IndirectHl)),
+    Instruction::new("RES 1,A", 2, 8, Operation::Res(1, Operand8::A)),
+    // 0x90
+    Instruction::new("RES 2,B", 2, 8, Operation::Res(2, Operand8::B)),
+    Instruction::new("RES 2,C", 2, 8, Operation::Res(2, Operand8::C)),
+    Instruction::new("RES 2,D", 2, 8, Operation::Res(2, Operand8::D)),
+    Instruction::new("RES 2,E", 2, 8, Operation::Res(2, Operand8::E)),
+    Instruction::new("RES 2,H", 2, 8, Operation::Res(2, Operand8::H)),
+    Instruction::new("RES 2,L", 2, 8, Operation::Res(2, Operand8::L)),
+    Instruction::new("RES 2,(HL)", 2, 16, Operation::Res(2, Operand8::IndirectHl)),
+    Instruction::new("RES 2,A", 2, 8, Operation::Res(2, Operand8::A)),
+    Instruction::new("RES 3,B", 2, 8, Operation::Res(3, Operand8::B)),
+    Instruction::new("RES 3,C", 2, 8, Operation::Res(3, Operand8::C)),
+    Instruction::new("RES 3,D", 2, 8, Operation::Res(3, Operand8::D)),
+    Instruction::new("RES 3,E", 2, 8, Operation::Res(3, Operand8::E)),
+    Instruction::new("RES 3,H", 2, 8, Operation::Res(3, Operand8::H)),
+    Instruction::new("RES 3,L", 2, 8, Operation::Res(3, Operand8::L)),
+    Instruction::new("RES 3,(HL)", 2, 16, Operation::Res(3, Operand8::IndirectHl)),
+    Instruction::new("RES 3,A", 2, 8, Operation::Res(3, Operand8::A)),
+    // 0xA0
+    Instruction::new("RES 4,B", 2, 8, Operation::Res(4, Operand8::B)),
+    Instruction::new("RES 4,C", 2, 8, Operation::Res(4, Operand8::C)),
+    Instruction::new("RES 4,D", 2, 8, Operation::Res(4, Operand8::D)),
+    Instruction::new("RES 4,E", 2, 8, Operation::Res(4, Operand8::E)),
+    Instruction::new("RES 4,H", 2, 8, Operation::Res(4, Operand8::H)),
+    Instruction::new("RES 4,L", 2, 8, Operation::Res(4, Operand8::L)),
+    Instruction::new("RES 4,(HL)", 2, 16, Operation::Res(4, Operand8::IndirectHl)),
+    Instruction::new("RES 4,A", 2, 8, Operation::Res(4, Operand8::A)),
+    Instruction::new("RES 5,B", 2, 8, Operation::Res(5, Operand8::B)),
+    Instruction::new("RES 5,C", 2, 8, Operation::Res(5, Operand8::C)),
+    Instruction::new("RES 5,D", 2, 8, Operation::Res(5, Operand8::D)),
+    Instruction::new("RES 5,E", 2, 8, Operation::Res(5, Operand8::E)),
+    Instruction::new("RES 5,H", 2, 8, Operation::Res(5, Operand8::H)),
+    Instruction::new("RES 5,L", 2, 8, Operation::Res(5, Operand8::L)),
+    Instruction::new("RES 5,(HL)", 2, 16, Operation::Res(5, Operand8::IndirectHl)),
+    Instruction::new("RES 5,A", 2, 8, Operation::Res(5, Operand8::A)),
+    // 0xB0
+    Instruction::new("RES 6,B", 2, 8, Operation::Res(6, Operand8::B)),
+    Instruction::new("RES 6,C", 2, 8, Operation::Res(6, Operand8::C)),
+    Instruction::new("RES 6,D", 2, 8, Operation::Res(6, Operand8::D)),
+    Instruction::new("RES 6,E", 2, 8, Operation::Res(6, Operand8::E)),
+    Instruction::new("RES 6,H", 2, 8, Operation::Res(6, Operand8::H)),
+    Instruction::new("RES 6,L", 2, 8, Operation::Res(6, Operand8::L)),
+    Instruction::new("RES 6,(HL)", 2, 16, Operation::Res(6, Operand8::IndirectHl)),
+    Instruction::new("RES 6,A", 2, 8, Operation::Res(6, Operand8::A)),
+    Instruction::new("RES 7,B", 2, 8, Operation::Res(7, Operand8::B)),
+    Instruction::new("RES 7,C", 2, 8, Operation::Res(7, Operand8::C)),
+    Instruction::new("RES 7,D", 2, 8, Operation::Res(7, Operand8::D)),
+    Instruction::new("RES 7,E", 2, 8, Operation::Res(7, Operand8::E)),
+    Instruction::new("RES 7,H", 2, 8, Operation::Res(7, Operand8::H)),
+    Instruction::new("RES 7,L", 2, 8, Operation::Res(7, Operand8::L)),
+    Instruction::new("RES 7,(HL)", 2, 16, Operation::Res(7, Operand8::IndirectHl)),
+    Instruction::new("RES 7,A", 2, 8, Operation::Res(7, Operand8::A)),
+    // 0xC0
+    Instruction::new("SET 0,B", 2, 8, Operation::Set(0, Operand8::B)),
+    Instruction::new("SET 0,C", 2, 8, Operation::Set(0, Operand8::C)),
+    Instruction::new("SET 0,D", 2, 8, Operation::Set(0, Operand8::D)),
+    Instruction::new("SET 0,E", 2, 8, Operation::Set(0, Operand8::E)),
+    Instruction::new("SET 0,H", 2, 8, Operation::Set(0, Operand8::H)),
+    Instruction::new("SET 0,L", 2, 8, Operation::Set(0, Operand8::L)),
+    Instruction::new("SET 0,(HL)", 2, 16, Operation::Set(0, Operand8::IndirectHl)),
+    Instruction::new("SET 0,A", 2, 8, Operation::Set(0, Operand8::A)),
+    Instruction::new("SET 1,B", 2, 8, Operation::Set(1, Operand8::B)),
+    Instruction::new("SET 1,C", 2, 8, Operation::Set(1, Operand8::C)),
+    Instruction::new("SET 1,D", 2, 8, Operation::Set(1, Operand8::D)),
+    Instruction::new("SET 1,E", 2, 8, Operation::Set(1, Operand8::E)),
+    Instruction::new("SET 1,H", 2, 8, Operation::Set(1, Operand8::H)),
+    Instruction::new("SET 1,L", 2, 8, Operation::Set(1, Operand8::L)),
+    Instruction::new("SET 1,(HL)", 2, 16, Operation::Set(1, Operand8::IndirectHl)),
+    Instruction::new("SET 1,A", 2, 8, Operation::Set(1, Operand8::A)),
+    // 0xD0
+    Instruction::new("SET 2,B", 2, 8, Operation::Set(2, Operand8::B)),
+    Instruction::new("SET 2,C", 2, 8, Operation::Set(2, Operand8::C)),
+    Instruction::new("SET 2,D", 2, 8, Operation::Set(2, Operand8::D)),
+    Instruction::new("SET 2,E", 2, 8, Operation::Set(2, Operand8::E)),
+    Instruction::new("SET 2,H", 2, 8, Operation::Set(2, Operand8::H)),
+    Instruction::new("SET 2,L", 2, 8, Operation::Set(2, Operand8::L)),
+    Instruction::new("SET 2,(HL)", 2, 16, Operation::Set(2, Operand8::IndirectHl)),
+    Instruction::new("SET 2,A", 2, 8, Operation::Set(2, Operand8::A)),
+    Instruction::new("SET 3,B", 2, 8, Operation::Set(3, Operand8::B)),
+    Instruction::new("SET 3,C", 2, 8, Operation::Set(3, Operand8::C)),
+    Instruction::new("SET 3,D", 2, 8, Operation::Set(3, Operand8::D)),
+    Instruction::new("SET 3,E", 2, 8, Operation::Set(3, Operand8::E)),
+    Instruction::new("SET 3,H", 2, 8, Operation::Set(3, Operand8::H)),
+    Instruction::new("SET 3,L", 2, 8, Operation::Set(3, Operand8::L)),
+    Instruction::new("SET 3,(HL)", 2, 16, Operation::Set(3, Operand8::IndirectHl)),
+    Instruction::new("SET 3,A", 2, 8, Operation::Set(3, Operand8::A)),
+    // 0xE0
+    Instruction::new("SET 4,B", 2, 8, Operation::Set(4, Operand8::B)),
+    Instruction::new("SET 4,C", 2, 8, Operation::Set(4, Operand8::C)),
+    Instruction::new("SET 4,D", 2, 8, Operation::Set(4, Operand8::D)),
+    Instruction::new("SET 4,E", 2, 8, Operation::Set(4, Operand8::E)),
+    Instruction::new("SET 4,H", 2, 8, Operation::Set(4, Operand8::H)),
+    Instruction::new("SET 4,L", 2, 8, Operation::Set(4, Operand8::L)),
+    Instruction::new("SET 4,(HL)", 2, 16, Operation::Set(4, Operand8::IndirectHl)),
+    Instruction::new("SET 4,A", 2, 8, Operation::Set(4, Operand8::A)),
+    Instruction::new("SET 5,B", 2, 8, Operation::Set(5, Operand8::B)),
+    Instruction::new("SET 5,C", 2, 8, Operation::Set(5, Operand8::C)),
+    Instruction::new("SET 5,D", 2, 8, Operation::Set(5, Operand8::D)),
+    Instruction::new("SET 5,E", 2, 8, Operation::Set(5, Operand8::E)),
+    Instruction::new("SET 5,H", 2, 8, Operation::Set(5, Operand8::H)),
+    Instruction::new("SET 5,L", 2, 8, Operation::Set(5, Operand8::L)),
+    Instruction::new("SET 5,(HL)", 2, 16, Operation::Set(5, Operand8::IndirectHl)),
+    Instruction::new("SET 5,A", 2, 8, Operation::Set(5, Operand8::A)),
+    // 0xF0
+    Instruction::new("SET 6,B", 2, 8, Operation::Set(6, Operand8::B)),
+    Instruction::new("SET 6,C", 2, 8, Operation::Set(6, Operand8::C)),
+    Instruction::new("SET 6,D", 2, 8, Operation::Set(6, Operand8::D)),
+    Instruction::new("SET 6,E", 2, 8, Operation::Set(6, Operand8::E)),
+    Instruction::new("SET 6,H", 2, 8, Operation::Set(6, Operand8::H)),
+    Instruction::new("SET 6,L", 2, 8, Operation::Set(6, Operand8::L)),
+    Instruction::new("SET 6,(HL)", 2, 16, Operation::Set(6, Operand8::IndirectHl)),
+    Instruction::new("SET 6,A", 2, 8, Operation::Set(6, Operand8::A)),
+    Instruction::new("SET 7,B", 2, 8, Operation::Set(7, Operand8::B)),
+    Instruction::new("SET 7,C", 2, 8, Operation::Set(7, Operand8::C)),
+    Instruction::new("SET 7,D", 2, 8, Operation::Set(7, Operand8::D)),
+    Instruction::new("SET 7,E", 2, 8, Operation::Set(7, Operand8::E)),
+    Instruction::new("SET 7,H", 2, 8, Operation::Set(7, Operand8::H)),
+    Instruction::new("SET 7,L", 2, 8, Operation::Set(7, Operand8::L)),
+    Instruction::new("SET 7,(HL)", 2, 16, Operation::Set(7, Operand8::IndirectHl)),
+    Instruction::new("SET 7,A", 2, 8, Operation::Set(7, Operand8::A)),
+];
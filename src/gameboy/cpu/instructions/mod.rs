@@ -1,22 +1,5 @@
-mod add;
-mod and;
-mod bit;
-mod compare;
-mod dec;
-pub mod decode;
-mod decode_cb;
-mod inc;
-mod jump;
-mod load;
+mod alu;
+mod execute;
+pub mod instruction;
+pub mod instruction_table;
 mod misc;
-mod or;
-mod reset;
-mod rlc;
-mod rrc;
-mod set;
-mod sla;
-mod sra;
-mod srl;
-mod sub;
-mod swap;
-mod xor;
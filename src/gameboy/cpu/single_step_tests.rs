@@ -0,0 +1,215 @@
+//! Runs the SM83 tests of the SingleStepTests project (https://github.com/SingleStepTests/sm83) against a flat 64 KiB memory. Each
+//! test sets up the registers and the memory, runs one instruction and compares the registers, the memory and the bus activity of
+//! every M-cycle.
+//!
+//! The test files are too large to keep in the repository. Point SM83_TESTS_DIR to the `v1` directory of a clone and run the ignored
+//! tests with `cargo test -- --ignored` to run them, the examples below always run.
+
+use std::path::Path;
+use std::{env, fs};
+
+use serde::Deserialize;
+
+use crate::gameboy::cpu::bus::flat_bus::FlatBus;
+use crate::gameboy::cpu::bus::recording_bus::{BusCycle, RecordingBus};
+use crate::gameboy::cpu::bus::Bus;
+use crate::gameboy::cpu::Cpu;
+
+/// Tests in the format of the SingleStepTests files. Idle M-cycles are listed with or without the address on the bus.
+const EXAMPLES: &str = r#"[
+    {
+        "name": "00 NOP",
+        "initial": {"pc": 49152, "sp": 65534, "a": 1, "b": 2, "c": 3, "d": 4, "e": 5, "f": 176, "h": 6, "l": 7, "ime": 0,
+            "ram": [[49152, 0]]},
+        "final": {"pc": 49153, "sp": 65534, "a": 1, "b": 2, "c": 3, "d": 4, "e": 5, "f": 176, "h": 6, "l": 7, "ime": 0,
+            "ram": [[49152, 0]]},
+        "cycles": [[49152, 0, "r-m"]]
+    },
+    {
+        "name": "77 LD (HL),A",
+        "initial": {"pc": 49152, "sp": 65534, "a": 66, "b": 0, "c": 0, "d": 0, "e": 0, "f": 0, "h": 208, "l": 0, "ime": 1,
+            "ram": [[49152, 119]]},
+        "final": {"pc": 49153, "sp": 65534, "a": 66, "b": 0, "c": 0, "d": 0, "e": 0, "f": 0, "h": 208, "l": 0, "ime": 1,
+            "ram": [[49152, 119], [53248, 66]]},
+        "cycles": [[49152, 119, "r-m"], [53248, 66, "-wm"]]
+    },
+    {
+        "name": "CD CALL a16",
+        "initial": {"pc": 49152, "sp": 57344, "a": 0, "b": 0, "c": 0, "d": 0, "e": 0, "f": 0, "h": 0, "l": 0, "ime": 0,
+            "ram": [[49152, 205], [49153, 52], [49154, 18]]},
+        "final": {"pc": 4660, "sp": 57342, "a": 0, "b": 0, "c": 0, "d": 0, "e": 0, "f": 0, "h": 0, "l": 0, "ime": 0,
+            "ram": [[49152, 205], [49153, 52], [49154, 18], [57343, 192], [57342, 3]]},
+        "cycles": [[49152, 205, "r-m"], [49153, 52, "r-m"], [49154, 18, "r-m"], [49154, null, "---"], [57343, 192, "-wm"],
+            [57342, 3, "-wm"]]
+    },
+    {
+        "name": "F5 PUSH AF",
+        "initial": {"pc": 49152, "sp": 57344, "a": 18, "b": 0, "c": 0, "d": 0, "e": 0, "f": 176, "h": 0, "l": 0, "ime": 0,
+            "ram": [[49152, 245]]},
+        "final": {"pc": 49153, "sp": 57342, "a": 18, "b": 0, "c": 0, "d": 0, "e": 0, "f": 176, "h": 0, "l": 0, "ime": 0,
+            "ram": [[49152, 245], [57343, 18], [57342, 176]]},
+        "cycles": [[49152, 245, "r-m"], null, [57343, 18, "-wm"], [57342, 176, "-wm"]]
+    },
+    {
+        "name": "18 JR r8",
+        "initial": {"pc": 49152, "sp": 65534, "a": 0, "b": 0, "c": 0, "d": 0, "e": 0, "f": 0, "h": 0, "l": 0, "ime": 0,
+            "ram": [[49152, 24], [49153, 254]]},
+        "final": {"pc": 49152, "sp": 65534, "a": 0, "b": 0, "c": 0, "d": 0, "e": 0, "f": 0, "h": 0, "l": 0, "ime": 0,
+            "ram": [[49152, 24], [49153, 254]]},
+        "cycles": [[49152, 24, "r-m"], [49153, 254, "r-m"], null]
+    },
+    {
+        "name": "CB 7E BIT 7,(HL)",
+        "initial": {"pc": 49152, "sp": 65534, "a": 0, "b": 0, "c": 0, "d": 0, "e": 0, "f": 64, "h": 208, "l": 0, "ime": 0,
+            "ram": [[49152, 203], [49153, 126], [53248, 128]]},
+        "final": {"pc": 49154, "sp": 65534, "a": 0, "b": 0, "c": 0, "d": 0, "e": 0, "f": 32, "h": 208, "l": 0, "ime": 0,
+            "ram": [[49152, 203], [49153, 126], [53248, 128]]},
+        "cycles": [[49152, 203, "r-m"], [49153, 126, "r-m"], [53248, 128, "r-m"]]
+    }
+]"#;
+
+#[derive(Deserialize)]
+struct TestCase {
+    name: String,
+    initial: CpuState,
+    #[serde(rename = "final")]
+    expected: CpuState,
+    /// Address, data and the read, write and memory request pins of every M-cycle
+    cycles: Vec<Option<(u16, Option<u8>, String)>>,
+}
+
+#[derive(Deserialize, PartialEq, Debug)]
+struct CpuState {
+    pc: u16,
+    sp: u16,
+    a: u8,
+    b: u8,
+    c: u8,
+    d: u8,
+    e: u8,
+    f: u8,
+    h: u8,
+    l: u8,
+    ime: u8,
+    ram: Vec<(u16, u8)>,
+}
+
+fn to_bus_cycle(cycle: &Option<(u16, Option<u8>, String)>) -> BusCycle {
+    let Some((address, Some(value), pins)) = cycle else {
+        return BusCycle::Idle;
+    };
+    let (address, value) = (*address, *value);
+    match pins.as_bytes() {
+        [b'r', ..] => BusCycle::Read { address, value },
+        [_, b'w', ..] => BusCycle::Write { address, value },
+        _ => BusCycle::Idle,
+    }
+}
+
+fn run_test(test: &TestCase) -> Result<(), String> {
+    let initial = &test.initial;
+    let mut bus = RecordingBus::new(FlatBus::new());
+    for &(address, value) in initial.ram.iter() {
+        bus.inner_mut().load(address, &[value]);
+    }
+
+    let mut cpu = Cpu::new();
+    cpu.register.pc = initial.pc;
+    cpu.register.sp = initial.sp;
+    cpu.register.a = initial.a;
+    cpu.register.b = initial.b;
+    cpu.register.c = initial.c;
+    cpu.register.d = initial.d;
+    cpu.register.e = initial.e;
+    cpu.register.set_f(initial.f);
+    cpu.register.h = initial.h;
+    cpu.register.l = initial.l;
+    cpu.register.set_interrupts_enabled(initial.ime > 0, true);
+
+    cpu.step(&mut bus);
+
+    // ONLY THE ADDRESSES THE TEST LISTS ARE COMPARED
+    let expected = &test.expected;
+    let actual = CpuState {
+        pc: cpu.register.pc,
+        sp: cpu.register.sp,
+        a: cpu.register.a,
+        b: cpu.register.b,
+        c: cpu.register.c,
+        d: cpu.register.d,
+        e: cpu.register.e,
+        f: cpu.register.get_f(),
+        h: cpu.register.h,
+        l: cpu.register.l,
+        ime: cpu.register.is_interrupts_enabled() as u8,
+        ram: expected
+            .ram
+            .iter()
+            .map(|&(address, _)| (address, bus.peek(address)))
+            .collect(),
+    };
+    if actual != *expected {
+        return Err(format!(
+            "{}: expected {expected:?}, got {actual:?}",
+            test.name
+        ));
+    }
+
+    let cycles = bus.take_cycles();
+    let expected_cycles: Vec<BusCycle> = test.cycles.iter().map(to_bus_cycle).collect();
+    if cycles != expected_cycles {
+        return Err(format!(
+            "{}: expected cycles {expected_cycles:?}, got {cycles:?}",
+            test.name
+        ));
+    }
+    Ok(())
+}
+
+/// Runs all tests and returns the failures.
+fn run_tests(tests: &[TestCase]) -> Vec<String> {
+    tests
+        .iter()
+        .filter_map(|test| run_test(test).err())
+        .collect()
+}
+
+#[test]
+fn examples() {
+    let tests: Vec<TestCase> = serde_json::from_str(EXAMPLES).unwrap();
+    let failures = run_tests(&tests);
+    assert!(failures.is_empty(), "{failures:#?}");
+}
+
+#[test]
+#[ignore = "needs SM83_TESTS_DIR"]
+fn single_step_tests() {
+    let directory = env::var_os("SM83_TESTS_DIR").expect("SM83_TESTS_DIR is not set");
+
+    let mut paths: Vec<_> = fs::read_dir(Path::new(&directory))
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| {
+            path.extension()
+                .is_some_and(|extension| extension == "json")
+        })
+        .collect();
+    paths.sort();
+
+    let mut failures = Vec::new();
+    for path in paths {
+        let tests: Vec<TestCase> = serde_json::from_slice(&fs::read(&path).unwrap()).unwrap();
+        let file_failures = run_tests(&tests);
+        if !file_failures.is_empty() {
+            println!(
+                "{}: {} of {} tests failed, first: {}",
+                path.display(),
+                file_failures.len(),
+                tests.len(),
+                file_failures[0]
+            );
+        }
+        failures.extend(file_failures);
+    }
+    assert!(failures.is_empty(), "{} tests failed", failures.len());
+}
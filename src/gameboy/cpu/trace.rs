@@ -0,0 +1,112 @@
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io;
+use std::io::{BufWriter, Write};
+use std::ops::RangeInclusive;
+
+use crate::gameboy::cpu::bus::Bus;
+use crate::gameboy::cpu::registers::Registers;
+
+/// Where the trace lines go
+pub enum TraceOutput {
+    File(BufWriter<File>),
+    /// Keeps only the most recent lines, e.g. to show what led up to a crash
+    RingBuffer {
+        lines: VecDeque<String>,
+        capacity: usize,
+    },
+}
+
+impl TraceOutput {
+    /// Creates or truncates the file.
+    pub fn file(path: &str) -> io::Result<Self> {
+        Ok(TraceOutput::File(BufWriter::new(File::create(path)?)))
+    }
+
+    pub fn ring_buffer(capacity: usize) -> Self {
+        TraceOutput::RingBuffer {
+            lines: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+}
+
+/// Logs the CPU state before every instruction in the format of Gameboy Doctor, so traces can be diffed against other emulators
+///
+/// A line looks like `A:01 F:B0 B:00 C:13 D:00 E:D8 H:01 L:4D SP:FFFE PC:0100 PCMEM:00,C3,13,02`.
+pub struct Tracer {
+    output: TraceOutput,
+    pc_range: RangeInclusive<u16>,
+    rom_bank: Option<usize>,
+}
+
+impl Tracer {
+    /// Traces every instruction until a filter is set.
+    pub fn new(output: TraceOutput) -> Self {
+        Self {
+            output,
+            pc_range: 0x0000..=0xFFFF,
+            rom_bank: None,
+        }
+    }
+
+    /// Only traces instructions whose address lies in the range.
+    pub fn set_pc_range(&mut self, pc_range: RangeInclusive<u16>) {
+        self.pc_range = pc_range;
+    }
+
+    /// Only traces instructions in the given ROM bank, see `Mbc::get_rom_bank`. Code running outside of ROM isn't traced then.
+    pub fn set_rom_bank(&mut self, rom_bank: Option<usize>) {
+        self.rom_bank = rom_bank;
+    }
+
+    /// Returns the lines of the ring buffer from oldest to newest. A file output has none.
+    pub fn lines(&self) -> impl Iterator<Item = &str> {
+        let lines = match &self.output {
+            TraceOutput::File(_) => None,
+            TraceOutput::RingBuffer { lines, .. } => Some(lines),
+        };
+        lines.into_iter().flatten().map(String::as_str)
+    }
+
+    /// Writes the line for the instruction at PC if it passes the filters.
+    pub fn trace<B: Bus>(&mut self, register: &Registers, bus: &B) -> io::Result<()> {
+        let pc = register.pc;
+        if !self.pc_range.contains(&pc) {
+            return Ok(());
+        }
+        if let Some(rom_bank) = self.rom_bank {
+            if pc >= 0x8000 || bus.get_rom_bank(pc) != rom_bank {
+                return Ok(());
+            }
+        }
+
+        let line = format!(
+            "A:{:02X} F:{:02X} B:{:02X} C:{:02X} D:{:02X} E:{:02X} H:{:02X} L:{:02X} SP:{:04X} PC:{:04X} PCMEM:{:02X},{:02X},{:02X},{:02X}",
+            register.a,
+            register.get_f(),
+            register.b,
+            register.c,
+            register.d,
+            register.e,
+            register.h,
+            register.l,
+            register.sp,
+            pc,
+            bus.peek(pc),
+            bus.peek(pc.wrapping_add(1)),
+            bus.peek(pc.wrapping_add(2)),
+            bus.peek(pc.wrapping_add(3)),
+        );
+        match &mut self.output {
+            TraceOutput::File(writer) => writeln!(writer, "{line}"),
+            TraceOutput::RingBuffer { lines, capacity } => {
+                if lines.len() >= *capacity {
+                    lines.pop_front();
+                }
+                lines.push_back(line);
+                Ok(())
+            }
+        }
+    }
+}
@@ -1,22 +1,99 @@
+use std::fs;
+use std::io::Write;
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+
+use crate::gameboy::cheat::cheat_engine::CheatEngine;
+use crate::gameboy::cheat::cheat_error::CheatError;
+use crate::gameboy::cheat::cheat_file;
+use crate::gameboy::cheat::memory_search::{MemorySearch, SearchFilter, SearchResult, ValueSize};
+use crate::gameboy::cpu::bus::tracing_bus::TracingBus;
+use crate::gameboy::cpu::cpu_event::CpuEvent;
+use crate::gameboy::cpu::trace::Tracer;
 use crate::gameboy::cpu::Cpu;
+use crate::gameboy::mbc::camera_image_source::CameraImageSource;
+use crate::gameboy::mbc::infrared::InfraredPeer;
+use crate::gameboy::mbc::mbc::Mbc;
+use crate::gameboy::mbc::rom_error::RomError;
 use crate::gameboy::mbc::rom_loader;
-use crate::gameboy::memory::interrupt_registers::InterruptRegisters;
+use crate::gameboy::memory::boot_rom::BootRom;
 use crate::gameboy::memory::memory;
 use crate::gameboy::memory::mmu::Mmu;
 use crate::gameboy::memory::random_access_memory::RandomAccessMemory;
 use crate::gameboy::memory::wram::Wram;
+use crate::gameboy::model::Model;
 
 pub struct Gameboy {
     cpu: Cpu,
     pub game_name: String,
     pub mmu: Mmu,
+    save_path: Option<PathBuf>,
+    saved_battery_ram: Vec<u8>,
+    frames_since_save: usize,
+    stopped_cycles: usize,
+    memory_search: Option<MemorySearch>,
+    bus_trace: Option<(RangeInclusive<u16>, Box<dyn Write>)>,
 }
 
+/// Battery RAM is written to disk every 5 seconds if it changed
+const FRAMES_PER_SAVE: usize = 300;
+
+const CYCLES_PER_FRAME: usize = 70224;
+
 impl Gameboy {
+    /// Same as `try_new`, but panics if the ROM can't be loaded.
     pub fn new(path: String) -> Self {
-        let mbc = rom_loader::load(path);
+        Self::try_new(path).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Loads the ROM at the given path and the battery RAM from the .sav file next to it.
+    pub fn try_new(path: String) -> Result<Self, RomError> {
+        Self::try_new_with_patch(path, None, None)
+    }
+
+    /// Same as `try_new`, but applies the given IPS, UPS or BPS patch to the ROM instead of looking for one next to it. The entry name
+    /// picks the ROM from a zip archive that holds more than one.
+    pub fn try_new_with_patch(
+        path: String,
+        entry_name: Option<&str>,
+        patch_path: Option<String>,
+    ) -> Result<Self, RomError> {
+        let mut mbc = rom_loader::load_with_patch(path.clone(), entry_name, patch_path)?;
+
+        // LOAD BATTERY RAM
+        let save_path = if mbc.has_battery() {
+            Some(Path::new(&path).with_extension("sav"))
+        } else {
+            None
+        };
+        if let Some(save_path) = save_path.as_ref() {
+            if let Ok(data) = fs::read(save_path) {
+                mbc.import_battery_ram(&data);
+            }
+        }
+
+        let mut gameboy = Self::with_mbc(mbc, save_path);
+
+        // LOAD CHEATS, A BROKEN CHEAT FILE SHOULDN'T KEEP THE GAME FROM STARTING
+        if let Some(cheat_path) = cheat_file::find_cheat_file(&path) {
+            if let Err(error) = gameboy.load_cheat_file(&cheat_path) {
+                println!("{}", error);
+            }
+        }
+
+        Ok(gameboy)
+    }
+
+    /// Creates a Gameboy from a ROM in memory. Battery RAM is not persisted.
+    pub fn from_rom_data(data: &[u8]) -> Result<Self, RomError> {
+        let mbc = rom_loader::load_from_bytes(data)?;
+        Ok(Self::with_mbc(mbc, None))
+    }
+
+    fn with_mbc(mbc: Box<dyn Mbc>, save_path: Option<PathBuf>) -> Self {
         let game_name = mbc.get_game_name();
-        let mut mmu = Mmu::new(mbc);
+        let saved_battery_ram = mbc.export_battery_ram();
+        let mut mmu = Mmu::new(mbc, BootRom::dmg());
 
         // ADD MEMORY UNITS
         mmu.add_memory_unit(Box::from(Wram::new()));
@@ -25,11 +102,9 @@ impl Gameboy {
             0xFF80,
             0xFFFF - 0xFF80,
         )));
-        mmu.add_memory_unit(Box::from(InterruptRegisters::new()));
         // TODO ADD JOYPAD
         // TODO ADD SERIAL BUS
         // TODO ADD APU
-        mmu.add_memory_unit(Box::from(RandomAccessMemory::new("KEY1", memory::KEY1, 1)));
         mmu.add_memory_unit(Box::from(RandomAccessMemory::new("VBK", memory::VBK, 1)));
         mmu.add_memory_unit(Box::from(RandomAccessMemory::new("HDMA", memory::HDMA1, 5)));
         mmu.add_memory_unit(Box::from(RandomAccessMemory::new("RP", memory::RP, 1)));
@@ -57,11 +132,182 @@ impl Gameboy {
             cpu: Cpu::new(),
             game_name,
             mmu,
+            save_path,
+            saved_battery_ram,
+            frames_since_save: 0,
+            stopped_cycles: 0,
+            memory_search: None,
+            bus_trace: None,
         }
     }
 
+    /// Replaces the built-in DMG boot ROM, e.g. with a user supplied MGB, SGB or CGB boot ROM. Has to be called before the first step.
+    pub fn set_boot_rom(&mut self, boot_rom: BootRom) {
+        self.cpu = Cpu::new();
+        self.mmu.set_boot_rom(boot_rom);
+    }
+
+    /// Starts the game at 0x0100 without running a boot ROM, with the register values the given model's boot ROM leaves behind.
+    /// Has to be called before the first step.
+    pub fn skip_boot(&mut self, model: Model) {
+        self.cpu.skip_boot(model);
+        self.mmu.skip_boot(model);
+    }
+
+    /// Runs one CPU instruction and returns true if a frame was completed during it.
     pub fn step(&mut self) -> bool {
+        if self.cpu.is_stopped() {
+            return self.step_stopped();
+        }
+
+        // THE MMU IS ONLY WRAPPED WHILE BUS TRACING IS ON, IT STOPS WHEN THE OUTPUT FAILS
+        match self.bus_trace.as_mut() {
+            Some((addresses, output)) => {
+                let mut bus = TracingBus::new(&mut self.mmu, addresses.clone(), output.as_mut());
+                self.cpu.step(&mut bus);
+                if let Some(error) = bus.take_error() {
+                    println!("Stopped bus tracing: {error}");
+                    self.bus_trace = None;
+                }
+            }
+            None => self.cpu.step(&mut self.mmu),
+        }
+        let vsync = self.mmu.take_vsync();
+
+        if vsync {
+            self.mmu.apply_cheat_writes();
+        }
+
+        if vsync && self.save_path.is_some() {
+            self.frames_since_save += 1;
+            if self.frames_since_save >= FRAMES_PER_SAVE {
+                self.save_battery_ram();
+            }
+        }
+
+        vsync
+    }
+
+    /// Returns the CPU events since the last call, e.g. a lock-up caused by an illegal opcode.
+    pub fn take_cpu_events(&mut self) -> Vec<CpuEvent> {
+        self.cpu.take_events()
+    }
+
+    /// Starts logging every instruction in the format of Gameboy Doctor, `None` stops it. See `Tracer` for the filters.
+    pub fn set_tracer(&mut self, tracer: Option<Tracer>) {
+        self.cpu.set_tracer(tracer);
+    }
+
+    /// Gives access to the tracer, e.g. to read the lines of its ring buffer.
+    pub fn get_tracer(&self) -> Option<&Tracer> {
+        self.cpu.get_tracer()
+    }
+
+    /// Logs the CPU's reads and writes in the address range to the output, e.g. to find the code that changes a variable. `None` stops
+    /// it.
+    pub fn set_bus_trace(&mut self, bus_trace: Option<(RangeInclusive<u16>, Box<dyn Write>)>) {
+        self.bus_trace = bus_trace;
+    }
+
+    pub fn is_bus_traced(&self) -> bool {
+        self.bus_trace.is_some()
+    }
+
+    /// Adds all cheats from the given cheat file, see `cheat_file::parse` for the format.
+    pub fn load_cheat_file(&mut self, path: &str) -> Result<(), CheatError> {
+        for cheat in cheat_file::load(path)? {
+            println!("Adding cheat {}", cheat.name);
+            self.mmu.cheats.add(cheat);
+        }
+        Ok(())
+    }
+
+    /// Gives access to the cheats to add, enable, disable and remove them while the game runs.
+    pub fn cheats(&mut self) -> &mut CheatEngine {
+        &mut self.mmu.cheats
+    }
+
+    /// Starts searching RAM for a value like the lives, see `MemorySearch`. A running search is replaced. The results can be turned
+    /// into cheats with `SearchResult::to_cheat` and added with `cheats`.
+    pub fn start_memory_search(&mut self, size: ValueSize) {
+        self.memory_search = Some(MemorySearch::new(&self.mmu, size));
+    }
+
+    /// Drops the addresses of the running search that don't match the filter and returns the remaining ones.
+    pub fn filter_memory_search(&mut self, filter: SearchFilter) -> &[SearchResult] {
+        match self.memory_search.as_mut() {
+            Some(search) => {
+                search.filter(&self.mmu, filter);
+                search.results()
+            }
+            None => &[],
+        }
+    }
+
+    pub fn get_memory_search(&self) -> Option<&MemorySearch> {
+        self.memory_search.as_ref()
+    }
+
+    /// In STOP mode all clocks are halted and the display is off. Frames are still reported, so the frontend keeps polling the joypad
+    /// that ends STOP mode.
+    fn step_stopped(&mut self) -> bool {
         self.cpu.step(&mut self.mmu);
-        self.mmu.step()
+        self.stopped_cycles += 4;
+        if self.stopped_cycles >= CYCLES_PER_FRAME {
+            self.stopped_cycles = 0;
+            return true;
+        }
+        false
+    }
+
+    /// Writes the battery backed cartridge RAM to the .sav file next to the ROM if it changed since the last save.
+    pub fn save_battery_ram(&mut self) {
+        self.frames_since_save = 0;
+        let Some(save_path) = self.save_path.as_ref() else {
+            return;
+        };
+
+        let battery_ram = self.mmu.get_mbc().export_battery_ram();
+        if battery_ram == self.saved_battery_ram {
+            return;
+        }
+
+        match fs::write(save_path, &battery_ram) {
+            Ok(()) => self.saved_battery_ram = battery_ram,
+            Err(error) => println!("Error writing file {}: {}", save_path.display(), error),
+        }
+    }
+
+    /// Returns true while the cartridge's rumble motor is switched on, frontends can use this to drive a rumble device.
+    pub fn is_rumble_active(&self) -> bool {
+        self.mmu.is_rumble_active()
+    }
+
+    /// Returns the tone the speaker of a HuC3 cartridge started since the last call. The tones are stored in the cartridge, so
+    /// frontends have to supply their own sound for each of them.
+    pub fn take_speaker_tone(&mut self) -> Option<u8> {
+        self.mmu.take_speaker_tone()
+    }
+
+    /// Tilts cartridges with an accelerometer like MBC7. Both axes range from -1.0 to 1.0, frontends can map keys, the mouse or an
+    /// analog stick to it.
+    pub fn set_tilt(&mut self, x: f32, y: f32) {
+        self.mmu.set_tilt(x, y);
+    }
+
+    /// Connects the infrared port of HuC1 and HuC3 cartridges, e.g. to another emulator instance through `LocalInfraredPeer::pair`.
+    pub fn connect_infrared(&mut self, peer: Box<dyn InfraredPeer>) {
+        self.mmu.connect_infrared(peer);
+    }
+
+    /// Sets what the Pocket Camera sees: a `StaticImageSource`, a `TestPatternSource` or a `FrameStreamSource` fed by the host.
+    pub fn set_camera_image_source(&mut self, source: Box<dyn CameraImageSource>) {
+        self.mmu.set_camera_image_source(source);
+    }
+}
+
+impl Drop for Gameboy {
+    fn drop(&mut self) {
+        self.save_battery_ram();
     }
 }
@@ -35,6 +35,11 @@ impl Joypad {
         );
     }
 
+    /// Returns true if a button of the selected type is pressed, which pulls its line in the joypad register low.
+    pub fn is_any_line_low(&self) -> bool {
+        self.register & 0b1111 != 0b1111
+    }
+
     fn update_input(&mut self) {
         let mut ff00 = self.register | 0b11000000;
         let type_selected = if ff00 & (1 << 5) == 0 {
@@ -0,0 +1,118 @@
+use std::fmt::{Display, Formatter};
+
+use crate::gameboy::mbc::cartridge_header::CartridgeHeader;
+use crate::gameboy::mbc::mbc::Mbc;
+use crate::gameboy::mbc::mbc5::Mbc5;
+use crate::gameboy::memory::memory::Memory;
+
+/// Source bit of every data bit read from 0x4000-0x7FFF, selected by the value written to 0x2001
+const DATA_REORDERING: [[u8; 8]; 8] = [
+    [0, 1, 2, 3, 4, 5, 6, 7],
+    [0, 1, 2, 3, 4, 5, 6, 7],
+    [0, 1, 2, 3, 4, 5, 6, 7],
+    [0, 1, 2, 3, 4, 5, 6, 7],
+    [0, 5, 1, 3, 4, 2, 6, 7],
+    [0, 4, 2, 3, 1, 5, 6, 7],
+    [0, 1, 2, 3, 4, 5, 6, 7],
+    [0, 1, 5, 3, 4, 2, 6, 7],
+];
+
+/// Source bit of every bank number bit written to 0x2000, selected by the value written to 0x2080
+const BANK_REORDERING: [[u8; 8]; 8] = [
+    [0, 1, 2, 3, 4, 5, 6, 7],
+    [0, 1, 2, 3, 4, 5, 6, 7],
+    [0, 1, 2, 3, 4, 5, 6, 7],
+    [3, 4, 2, 0, 1, 5, 6, 7],
+    [0, 1, 2, 3, 4, 5, 6, 7],
+    [1, 2, 3, 4, 0, 5, 6, 7],
+    [0, 1, 2, 3, 4, 5, 6, 7],
+    [0, 1, 2, 3, 4, 5, 6, 7],
+];
+
+/// Unlicensed MBC5 clone by BBD
+///
+/// As a copy protection the game scrambles the bits of the switchable ROM bank and of the bank numbers it writes. The order is selected
+/// by two extra registers at 0x2001 and 0x2080.
+pub struct Bbd {
+    mbc5: Mbc5,
+    data_swap_mode: usize,
+    bank_swap_mode: usize,
+}
+
+impl Bbd {
+    pub fn new(cartridge_data: &[u8], header: CartridgeHeader) -> Self {
+        Self {
+            mbc5: Mbc5::new(cartridge_data, header, false),
+            data_swap_mode: 0,
+            bank_swap_mode: 0,
+        }
+    }
+
+    /// BBD cartridges claim to be MBC5 cartridges, their startup code gives them away by setting up both swap registers.
+    pub fn detect(data: &[u8], header: &CartridgeHeader) -> bool {
+        // LD (0x2001),A AND LD (0x2080),A
+        let swap_writes: [&[u8]; 2] = [&[0xEA, 0x01, 0x20], &[0xEA, 0x80, 0x20]];
+        let bank0 = &data[0..data.len().min(0x4000)];
+        (0x19..=0x1E).contains(&header.cartridge_type)
+            && swap_writes
+                .iter()
+                .all(|code| bank0.windows(code.len()).any(|window| window == *code))
+    }
+}
+
+fn reorder_bits(value: u8, order: &[u8; 8]) -> u8 {
+    order.iter().enumerate().fold(0, |result, (bit, &source)| {
+        result | ((value >> source) & 0b1) << bit
+    })
+}
+
+impl Memory for Bbd {
+    fn accepts_address(&self, address: u16) -> bool {
+        self.mbc5.accepts_address(address)
+    }
+
+    fn read_byte(&self, address: u16) -> u8 {
+        let value = self.mbc5.read_byte(address);
+        if (0x4000..0x8000).contains(&address) {
+            return reorder_bits(value, &DATA_REORDERING[self.data_swap_mode]);
+        }
+        value
+    }
+
+    fn write_byte(&mut self, address: u16, value: u8) {
+        match address & 0xF0FF {
+            0x2000 => {
+                // SELECT SCRAMBLED ROM BANK NUMBER
+                let bank = reorder_bits(value, &BANK_REORDERING[self.bank_swap_mode]);
+                self.mbc5.write_byte(address, bank);
+            }
+            0x2001 => self.data_swap_mode = (value & 0b111) as usize,
+            0x2080 => self.bank_swap_mode = (value & 0b111) as usize,
+            _ => self.mbc5.write_byte(address, value),
+        }
+    }
+}
+
+impl Display for Bbd {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Bbd running game {}", self.get_game_name())
+    }
+}
+
+impl Mbc for Bbd {
+    fn get_header(&self) -> &CartridgeHeader {
+        self.mbc5.get_header()
+    }
+
+    fn get_rom_bank(&self, address: u16) -> usize {
+        self.mbc5.get_rom_bank(address)
+    }
+
+    fn export_battery_ram(&self) -> Vec<u8> {
+        self.mbc5.export_battery_ram()
+    }
+
+    fn import_battery_ram(&mut self, data: &[u8]) {
+        self.mbc5.import_battery_ram(data);
+    }
+}
@@ -0,0 +1,172 @@
+use std::fs;
+use std::io;
+use std::sync::mpsc::Receiver;
+
+/// Width of the image the Pocket Camera hands to the game
+pub const CAMERA_WIDTH: usize = 128;
+
+/// Height of the image the Pocket Camera hands to the game
+pub const CAMERA_HEIGHT: usize = 112;
+
+/// Brightness of every pixel, 0 is black and 255 is white. Rows are stored one after another.
+pub type CameraImage = [u8; CAMERA_WIDTH * CAMERA_HEIGHT];
+
+/// Provides the picture the Pocket Camera's sensor sees whenever the game takes a photo.
+pub trait CameraImageSource {
+    fn capture(&mut self) -> CameraImage;
+}
+
+/// Always shows the same picture, e.g. one loaded from a file.
+pub struct StaticImageSource {
+    image: CameraImage,
+}
+
+impl StaticImageSource {
+    /// Uses a grayscale image of any size, it is scaled to 128x112 pixels.
+    pub fn new(pixels: &[u8], width: usize, height: usize) -> Self {
+        let mut image = [0; CAMERA_WIDTH * CAMERA_HEIGHT];
+        if width > 0 && height > 0 && pixels.len() >= width * height {
+            for y in 0..CAMERA_HEIGHT {
+                for x in 0..CAMERA_WIDTH {
+                    let source_x = x * width / CAMERA_WIDTH;
+                    let source_y = y * height / CAMERA_HEIGHT;
+                    image[y * CAMERA_WIDTH + x] = pixels[source_y * width + source_x];
+                }
+            }
+        }
+        Self { image }
+    }
+
+    /// Loads a binary (P5) or ASCII (P2) PGM image.
+    pub fn load(path: &str) -> io::Result<Self> {
+        let data = fs::read(path)?;
+        let (pixels, width, height) = parse_pgm(&data)?;
+        Ok(Self::new(&pixels, width, height))
+    }
+}
+
+impl CameraImageSource for StaticImageSource {
+    fn capture(&mut self) -> CameraImage {
+        self.image
+    }
+}
+
+/// Generates vertical gray bars with a diagonal gradient, so captures can be checked without any real image.
+pub struct TestPatternSource {
+    frame: usize,
+}
+
+impl TestPatternSource {
+    pub fn new() -> Self {
+        Self { frame: 0 }
+    }
+}
+
+impl CameraImageSource for TestPatternSource {
+    fn capture(&mut self) -> CameraImage {
+        let mut image = [0; CAMERA_WIDTH * CAMERA_HEIGHT];
+        for y in 0..CAMERA_HEIGHT {
+            for x in 0..CAMERA_WIDTH {
+                // THE TOP HALF SHOWS 8 BARS, THE BOTTOM HALF A GRADIENT THAT MOVES WITH EVERY CAPTURE
+                image[y * CAMERA_WIDTH + x] = if y < CAMERA_HEIGHT / 2 {
+                    (x / 16 * 255 / 7) as u8
+                } else {
+                    ((x + y + self.frame) % 256) as u8
+                };
+            }
+        }
+        self.frame = self.frame.wrapping_add(1);
+        image
+    }
+}
+
+/// Shows frames that the host sends through a channel, e.g. from a webcam. The last received frame is kept until a new one arrives.
+pub struct FrameStreamSource {
+    receiver: Receiver<CameraImage>,
+    last_frame: CameraImage,
+}
+
+impl FrameStreamSource {
+    pub fn new(receiver: Receiver<CameraImage>) -> Self {
+        Self {
+            receiver,
+            last_frame: [0; CAMERA_WIDTH * CAMERA_HEIGHT],
+        }
+    }
+}
+
+impl CameraImageSource for FrameStreamSource {
+    fn capture(&mut self) -> CameraImage {
+        while let Ok(frame) = self.receiver.try_recv() {
+            self.last_frame = frame;
+        }
+        self.last_frame
+    }
+}
+
+fn parse_pgm(data: &[u8]) -> io::Result<(Vec<u8>, usize, usize)> {
+    let invalid = |reason: &str| io::Error::new(io::ErrorKind::InvalidData, reason.to_string());
+
+    // THE HEADER CONSISTS OF 4 WHITESPACE SEPARATED TOKENS, COMMENTS START WITH #
+    let mut tokens = Vec::new();
+    let mut position = 0;
+    while tokens.len() < 4 {
+        while position < data.len() && data[position].is_ascii_whitespace() {
+            position += 1;
+        }
+        if position < data.len() && data[position] == b'#' {
+            while position < data.len() && data[position] != b'\n' {
+                position += 1;
+            }
+            continue;
+        }
+        let start = position;
+        while position < data.len() && !data[position].is_ascii_whitespace() {
+            position += 1;
+        }
+        if start == position {
+            return Err(invalid("PGM header is truncated"));
+        }
+        tokens.push(String::from_utf8_lossy(&data[start..position]).to_string());
+    }
+    let number = |token: &str| {
+        token
+            .parse::<usize>()
+            .map_err(|_| invalid("PGM header contains an invalid number"))
+    };
+    let width = number(&tokens[1])?;
+    let height = number(&tokens[2])?;
+    let max_value = number(&tokens[3])?.clamp(1, 255);
+
+    // EVERY PIXEL TAKES AT LEAST ONE BYTE, SO A SIZE BEYOND THE DATA CAN BE REJECTED BEFORE ANYTHING IS ALLOCATED FOR IT
+    let pixel_count = width
+        .checked_mul(height)
+        .filter(|&pixel_count| pixel_count <= data.len())
+        .ok_or_else(|| invalid("PGM image size doesn't match the data"))?;
+
+    let pixels: Vec<usize> = match tokens[0].as_str() {
+        // A SINGLE WHITESPACE SEPARATES THE HEADER FROM THE BINARY PIXELS
+        "P5" => position
+            .checked_add(1 + pixel_count)
+            .and_then(|end| data.get(position + 1..end))
+            .ok_or_else(|| invalid("PGM image is truncated"))?
+            .iter()
+            .map(|&pixel| pixel as usize)
+            .collect(),
+        "P2" => String::from_utf8_lossy(&data[position..])
+            .split_ascii_whitespace()
+            .take(pixel_count)
+            .map(number)
+            .collect::<io::Result<_>>()?,
+        _ => return Err(invalid("only P2 and P5 PGM images are supported")),
+    };
+    if pixels.len() < pixel_count {
+        return Err(invalid("PGM image is truncated"));
+    }
+
+    let pixels = pixels
+        .iter()
+        .map(|&pixel| (pixel.min(max_value) * 255 / max_value) as u8)
+        .collect();
+    Ok((pixels, width, height))
+}
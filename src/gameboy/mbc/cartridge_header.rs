@@ -0,0 +1,261 @@
+use std::fmt::{Display, Formatter};
+
+use crate::gameboy::mbc::mbc;
+use crate::gameboy::mbc::rom_error::RomError;
+
+pub const LOGO: usize = 0x0104;
+pub const TITLE: usize = 0x0134;
+pub const MANUFACTURER_CODE: usize = 0x013F;
+pub const CGB_FLAG: usize = 0x0143;
+pub const NEW_LICENSEE_CODE: usize = 0x0144;
+pub const SGB_FLAG: usize = 0x0146;
+pub const CARTRIDGE_TYPE: usize = 0x0147;
+pub const ROM_SIZE: usize = 0x0148;
+pub const RAM_SIZE: usize = 0x0149;
+pub const DESTINATION_CODE: usize = 0x014A;
+pub const OLD_LICENSEE_CODE: usize = 0x014B;
+pub const MASK_ROM_VERSION: usize = 0x014C;
+pub const HEADER_CHECKSUM: usize = 0x014D;
+pub const GLOBAL_CHECKSUM: usize = 0x014E;
+
+/// The header ends right before the entry point of the game code at 0x0150
+pub const HEADER_END: usize = 0x0150;
+
+const ROM_BANK_SIZE: usize = 0x4000;
+
+/// The largest ROM size a header can declare, 512 banks of 16 KiB
+pub const MAX_ROM_SIZE: usize = 0x80_0000;
+
+/// An old licensee code of 0x33 means the new licensee code is used instead
+const USE_NEW_LICENSEE_CODE: u8 = 0x33;
+
+/// Decoded cartridge header at 0x0100-0x014F of every ROM
+#[derive(Clone)]
+pub struct CartridgeHeader {
+    pub title: String,
+    pub manufacturer_code: Option<String>,
+    pub cgb_support: CgbSupport,
+    pub sgb_support: bool,
+    pub cartridge_type: u8,
+    pub old_licensee_code: u8,
+    pub new_licensee_code: Option<String>,
+    pub rom_size: usize,
+    pub ram_size: usize,
+    pub destination: Destination,
+    pub version: u8,
+    pub header_checksum: u8,
+    pub global_checksum: u16,
+    logo_valid: bool,
+    computed_header_checksum: u8,
+    computed_global_checksum: u16,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum CgbSupport {
+    /// Monochrome game, CGBs run it in compatibility mode
+    None,
+    /// Game uses CGB features but works on monochrome models too
+    Enhanced,
+    /// Game only runs on a CGB
+    Required,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Destination {
+    Japan,
+    Overseas,
+}
+
+impl CartridgeHeader {
+    /// Parses the header of the given ROM. Fails if the ROM is too short to contain a header or if the ROM or RAM size is unknown.
+    pub fn parse(rom: &[u8]) -> Result<Self, RomError> {
+        if rom.len() < HEADER_END {
+            return Err(RomError::Truncated {
+                length: rom.len(),
+                expected: HEADER_END,
+            });
+        }
+
+        let cgb_support = match rom[CGB_FLAG] {
+            0xC0 => CgbSupport::Required,
+            flag if flag & 0x80 > 0 => CgbSupport::Enhanced,
+            _ => CgbSupport::None,
+        };
+
+        // NEWER CARTRIDGES SHORTEN THE TITLE TO MAKE ROOM FOR THE MANUFACTURER CODE AND THE CGB FLAG
+        let manufacturer_code = if cgb_support != CgbSupport::None {
+            let code = &rom[MANUFACTURER_CODE..CGB_FLAG];
+            if code
+                .iter()
+                .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+            {
+                Some(String::from_utf8_lossy(code).into())
+            } else {
+                None
+            }
+        } else {
+            None
+        };
+        let title_end = match (&manufacturer_code, cgb_support) {
+            (Some(_), _) => MANUFACTURER_CODE,
+            (None, CgbSupport::None) => CGB_FLAG + 1,
+            (None, _) => CGB_FLAG,
+        };
+        let title = rom[TITLE..title_end]
+            .iter()
+            .take_while(|&&c| c != 0)
+            .map(|&c| c as char)
+            .collect::<String>()
+            .trim_end()
+            .to_string();
+
+        let old_licensee_code = rom[OLD_LICENSEE_CODE];
+        let new_licensee_code = if old_licensee_code == USE_NEW_LICENSEE_CODE {
+            Some(String::from_utf8_lossy(&rom[NEW_LICENSEE_CODE..NEW_LICENSEE_CODE + 2]).into())
+        } else {
+            None
+        };
+
+        let rom_size = match rom[ROM_SIZE] {
+            code @ 0x00..=0x08 => 0x8000 << code,
+            0x52 => 72 * ROM_BANK_SIZE,
+            0x53 => 80 * ROM_BANK_SIZE,
+            0x54 => 96 * ROM_BANK_SIZE,
+            code => {
+                return Err(RomError::BadHeader(format!(
+                    "unknown ROM size {:#04X}",
+                    code
+                )));
+            }
+        };
+
+        let ram_size = match rom[RAM_SIZE] {
+            0x00 => 0,
+            0x01 => 0x800,
+            0x02 => 0x2000,
+            0x03 => 0x8000,
+            0x04 => 0x20000,
+            0x05 => 0x10000,
+            code => {
+                return Err(RomError::BadHeader(format!(
+                    "unknown RAM size {:#04X}",
+                    code
+                )));
+            }
+        };
+
+        let header_checksum = rom[HEADER_CHECKSUM];
+        let computed_header_checksum = rom[TITLE..HEADER_CHECKSUM]
+            .iter()
+            .fold(0u8, |checksum, &byte| {
+                checksum.wrapping_sub(byte).wrapping_sub(1)
+            });
+
+        // THE GLOBAL CHECKSUM SUMS UP ALL BYTES OF THE ROM EXCEPT FOR THE CHECKSUM ITSELF
+        let global_checksum = u16::from_be_bytes([rom[GLOBAL_CHECKSUM], rom[GLOBAL_CHECKSUM + 1]]);
+        let computed_global_checksum = rom
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| *index != GLOBAL_CHECKSUM && *index != GLOBAL_CHECKSUM + 1)
+            .fold(0u16, |checksum, (_, &byte)| {
+                checksum.wrapping_add(byte as u16)
+            });
+
+        Ok(Self {
+            title,
+            manufacturer_code,
+            cgb_support,
+            sgb_support: rom[SGB_FLAG] == 0x03,
+            cartridge_type: rom[CARTRIDGE_TYPE],
+            old_licensee_code,
+            new_licensee_code,
+            rom_size,
+            ram_size,
+            destination: if rom[DESTINATION_CODE] == 0x00 {
+                Destination::Japan
+            } else {
+                Destination::Overseas
+            },
+            version: rom[MASK_ROM_VERSION],
+            header_checksum,
+            global_checksum,
+            logo_valid: rom[LOGO..LOGO + mbc::NINTENDO_LOGO.len()] == mbc::NINTENDO_LOGO,
+            computed_header_checksum,
+            computed_global_checksum,
+        })
+    }
+
+    pub fn rom_bank_count(&self) -> usize {
+        self.rom_size / ROM_BANK_SIZE
+    }
+
+    /// Returns true if the cartridge keeps its RAM alive with a battery.
+    pub fn has_battery(&self) -> bool {
+        matches!(
+            self.cartridge_type,
+            0x03 | 0x06
+                | 0x09
+                | 0x0D
+                | 0x0F
+                | 0x10
+                | 0x13
+                | 0x1B
+                | 0x1E
+                | 0x22
+                | 0xFC
+                | 0xFD
+                | 0xFE
+                | 0xFF
+        )
+    }
+
+    /// The boot ROM refuses to start a cartridge without the correct logo.
+    pub fn is_logo_valid(&self) -> bool {
+        self.logo_valid
+    }
+
+    /// The boot ROM refuses to start a cartridge with a wrong header checksum.
+    pub fn is_header_checksum_valid(&self) -> bool {
+        self.header_checksum == self.computed_header_checksum
+    }
+
+    /// Real hardware never verifies the global checksum, a mismatch usually indicates a bad or modified dump though.
+    pub fn is_global_checksum_valid(&self) -> bool {
+        self.global_checksum == self.computed_global_checksum
+    }
+
+    /// Returns a description of every problem found in the header, an empty list means the header is intact.
+    pub fn validate(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+        if !self.logo_valid {
+            problems.push(String::from("Nintendo logo does not match"));
+        }
+        if !self.is_header_checksum_valid() {
+            problems.push(format!(
+                "header checksum mismatch (stored {:#04X}, computed {:#04X})",
+                self.header_checksum, self.computed_header_checksum
+            ));
+        }
+        if !self.is_global_checksum_valid() {
+            problems.push(format!(
+                "global checksum mismatch (stored {:#06X}, computed {:#06X})",
+                self.global_checksum, self.computed_global_checksum
+            ));
+        }
+        problems
+    }
+}
+
+impl Display for CartridgeHeader {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} (type {:#04X}, {} KiB ROM, {} KiB RAM, version {})",
+            self.title,
+            self.cartridge_type,
+            self.rom_size / 1024,
+            self.ram_size / 1024,
+            self.version
+        )
+    }
+}
@@ -0,0 +1,147 @@
+use std::cmp::min;
+use std::fmt::{Display, Formatter};
+
+use crate::gameboy::mbc::cartridge_header::CartridgeHeader;
+use crate::gameboy::mbc::infrared::{InfraredPeer, NoInfraredPeer};
+use crate::gameboy::mbc::mbc::Mbc;
+use crate::gameboy::memory::memory::Memory;
+
+const ROM_BANK_SIZE: usize = 0x4000;
+const RAM_BANK_SIZE: usize = 0x2000;
+
+/// Writing this value to 0x0000-0x1FFF maps the infrared port to 0xA000-0xBFFF, any other value maps the RAM
+const IR_MODE: u8 = 0x0E;
+
+/// Hudson HuC1, an MBC1 clone with an infrared LED and receiver instead of the RAM enable register
+pub struct HuC1 {
+    rom_banks: Vec<[u8; ROM_BANK_SIZE]>,
+    ram_banks: Box<[[u8; RAM_BANK_SIZE]; 4]>,
+    ram_size: usize,
+    rom_bank: usize,
+    ram_bank: usize,
+    ir_mode: bool,
+    infrared: Box<dyn InfraredPeer>,
+    header: CartridgeHeader,
+}
+
+impl HuC1 {
+    pub fn new(cartridge_data: &[u8], header: CartridgeHeader) -> Self {
+        let bank_count = header.rom_bank_count().clamp(2, 64);
+        let mut result = Self {
+            rom_banks: vec![[0; ROM_BANK_SIZE]; bank_count],
+            ram_banks: Box::new([[0; RAM_BANK_SIZE]; 4]),
+            ram_size: 0,
+            rom_bank: 1,
+            ram_bank: 0,
+            ir_mode: false,
+            infrared: Box::new(NoInfraredPeer),
+            header,
+        };
+
+        // COPY ROM BANKS
+        for (bank_index, bank_data) in cartridge_data
+            .chunks(ROM_BANK_SIZE)
+            .take(bank_count)
+            .enumerate()
+        {
+            result.rom_banks[bank_index][0..bank_data.len()].copy_from_slice(bank_data);
+        }
+        result.ram_size = min(
+            result.header.ram_size,
+            RAM_BANK_SIZE * result.ram_banks.len(),
+        );
+
+        result
+    }
+}
+
+impl Memory for HuC1 {
+    fn accepts_address(&self, address: u16) -> bool {
+        address < 0x8000 || (0xA000..0xC000).contains(&address)
+    }
+
+    fn read_byte(&self, address: u16) -> u8 {
+        if address < 0x4000 {
+            return self.rom_banks[0][address as usize];
+        }
+
+        if (0x4000..0x8000).contains(&address) {
+            let bank = self.rom_bank % self.rom_banks.len();
+            return self.rom_banks[bank][(address - 0x4000) as usize];
+        }
+
+        if (0xA000..0xC000).contains(&address) {
+            if self.ir_mode {
+                // BIT 0 IS SET WHILE THE RECEIVER SEES LIGHT
+                return 0xC0 | self.infrared.is_receiving() as u8;
+            }
+            if self.ram_size == 0 {
+                return 0xFF;
+            }
+            return self.ram_banks[self.ram_bank][(address - 0xA000) as usize];
+        }
+
+        0xFF
+    }
+
+    fn write_byte(&mut self, address: u16, value: u8) {
+        if address < 0x2000 {
+            // SWITCH BETWEEN RAM AND INFRARED PORT
+            self.ir_mode = value == IR_MODE;
+        } else if (0x2000..0x4000).contains(&address) {
+            // SELECT ROM BANK NUMBER
+            let bank = value & 0b11_1111;
+            self.rom_bank = if bank == 0 { 1 } else { bank as usize };
+        } else if (0x4000..0x6000).contains(&address) {
+            // SELECT RAM BANK NUMBER
+            self.ram_bank = (value & 0b11) as usize;
+        } else if (0xA000..0xC000).contains(&address) {
+            if self.ir_mode {
+                // BIT 0 SWITCHES THE LED
+                self.infrared.set_led(value & 0b1 > 0);
+            } else if self.ram_size > 0 {
+                self.ram_banks[self.ram_bank][(address - 0xA000) as usize] = value;
+            }
+        }
+    }
+}
+
+impl Display for HuC1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "HuC1 running game {}", self.get_game_name())
+    }
+}
+
+impl Mbc for HuC1 {
+    fn get_header(&self) -> &CartridgeHeader {
+        &self.header
+    }
+
+    fn get_rom_bank(&self, address: u16) -> usize {
+        if address < 0x4000 {
+            0
+        } else {
+            self.rom_bank % self.rom_banks.len()
+        }
+    }
+
+    fn connect_infrared(&mut self, peer: Box<dyn InfraredPeer>) {
+        self.infrared = peer;
+    }
+
+    fn export_battery_ram(&self) -> Vec<u8> {
+        self.ram_banks
+            .iter()
+            .flatten()
+            .take(self.ram_size)
+            .copied()
+            .collect()
+    }
+
+    fn import_battery_ram(&mut self, data: &[u8]) {
+        let ram = self.ram_banks.iter_mut().flatten().take(self.ram_size);
+        for (target, source) in ram.zip(data) {
+            *target = *source;
+        }
+    }
+}
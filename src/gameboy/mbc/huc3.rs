@@ -0,0 +1,247 @@
+use std::cmp::min;
+use std::fmt::{Display, Formatter};
+
+use crate::gameboy::mbc::cartridge_header::CartridgeHeader;
+use crate::gameboy::mbc::huc3_clock::HuC3Clock;
+use crate::gameboy::mbc::infrared::{InfraredPeer, NoInfraredPeer};
+use crate::gameboy::mbc::mbc::Mbc;
+use crate::gameboy::memory::memory::Memory;
+
+const ROM_BANK_SIZE: usize = 0x4000;
+const RAM_BANK_SIZE: usize = 0x2000;
+
+/// The value written to 0x0000-0x1FFF selects what 0xA000-0xBFFF maps to
+const MODE_RAM_READ_ONLY: u8 = 0x0;
+const MODE_RAM: u8 = 0xA;
+const MODE_RTC_COMMAND: u8 = 0xB;
+const MODE_RTC_RESPONSE: u8 = 0xC;
+const MODE_RTC_SEMAPHORE: u8 = 0xD;
+const MODE_IR: u8 = 0xE;
+
+const COMMAND_READ: u8 = 0x1;
+const COMMAND_WRITE: u8 = 0x3;
+const COMMAND_ADDRESS_LOW: u8 = 0x4;
+const COMMAND_ADDRESS_HIGH: u8 = 0x5;
+const COMMAND_EXTENDED: u8 = 0x6;
+
+/// The tone generator plays the tone selected at this address of the RTC memory
+const TONE_ADDRESS: usize = 0x27;
+
+/// Hudson HuC3 with a real time clock, a piezo speaker and an infrared port
+///
+/// The clock is talked to through 4 bit commands: the game writes a command in RTC command mode and then starts it by clearing the
+/// semaphore. Commands read and write a memory of 256 nibbles, the current time can be copied between the clock and that memory.
+/// Another command starts the speaker's tone generator, the frontend is told which tone to play.
+pub struct HuC3 {
+    rom_banks: Vec<[u8; ROM_BANK_SIZE]>,
+    ram_banks: Box<[[u8; RAM_BANK_SIZE]; 4]>,
+    ram_size: usize,
+    rom_bank: usize,
+    ram_bank: usize,
+    mode: u8,
+    clock: HuC3Clock,
+    rtc_memory: [u8; 256],
+    rtc_address: u8,
+    pending_command: u8,
+    response: u8,
+    speaker_tone: Option<u8>,
+    infrared: Box<dyn InfraredPeer>,
+    header: CartridgeHeader,
+}
+
+impl HuC3 {
+    pub fn new(cartridge_data: &[u8], header: CartridgeHeader) -> Self {
+        let bank_count = header.rom_bank_count().clamp(2, 128);
+        let mut result = Self {
+            rom_banks: vec![[0; ROM_BANK_SIZE]; bank_count],
+            ram_banks: Box::new([[0; RAM_BANK_SIZE]; 4]),
+            ram_size: 0,
+            rom_bank: 1,
+            ram_bank: 0,
+            mode: MODE_RAM_READ_ONLY,
+            clock: HuC3Clock::new(),
+            rtc_memory: [0; 256],
+            rtc_address: 0,
+            pending_command: 0,
+            response: 0,
+            speaker_tone: None,
+            infrared: Box::new(NoInfraredPeer),
+            header,
+        };
+
+        // COPY ROM BANKS
+        for (bank_index, bank_data) in cartridge_data
+            .chunks(ROM_BANK_SIZE)
+            .take(bank_count)
+            .enumerate()
+        {
+            result.rom_banks[bank_index][0..bank_data.len()].copy_from_slice(bank_data);
+        }
+        result.ram_size = min(
+            result.header.ram_size,
+            RAM_BANK_SIZE * result.ram_banks.len(),
+        );
+
+        result
+    }
+
+    fn execute_command(&mut self) {
+        let command = (self.pending_command >> 4) & 0b111;
+        let argument = self.pending_command & 0xF;
+        match command {
+            COMMAND_READ => {
+                self.response = self.rtc_memory[self.rtc_address as usize];
+                self.rtc_address = self.rtc_address.wrapping_add(1);
+            }
+            COMMAND_WRITE => {
+                self.rtc_memory[self.rtc_address as usize] = argument;
+                self.rtc_address = self.rtc_address.wrapping_add(1);
+            }
+            COMMAND_ADDRESS_LOW => {
+                self.rtc_address = (self.rtc_address & 0xF0) | argument;
+            }
+            COMMAND_ADDRESS_HIGH => {
+                self.rtc_address = (self.rtc_address & 0x0F) | (argument << 4);
+            }
+            COMMAND_EXTENDED => match argument {
+                // COPY THE CURRENT TIME TO 0x00-0x06
+                0x0 => {
+                    self.rtc_memory[0..7].copy_from_slice(&self.clock.to_nibbles());
+                }
+                // SET THE CLOCK FROM 0x00-0x06
+                0x1 => {
+                    let mut nibbles = [0; 7];
+                    nibbles.copy_from_slice(&self.rtc_memory[0..7]);
+                    self.clock.set_from_nibbles(&nibbles);
+                }
+                // STATUS, THE CLOCK IS ALWAYS READY
+                0x2 => {
+                    self.response = 0x1;
+                }
+                // START THE TONE GENERATOR, THE TONE IS LATCHED FOR THE FRONTEND TO PLAY
+                0xE => {
+                    self.speaker_tone = Some(self.rtc_memory[TONE_ADDRESS]);
+                }
+                // NO OTHER EXTENDED COMMANDS ARE KNOWN
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+}
+
+impl Memory for HuC3 {
+    fn accepts_address(&self, address: u16) -> bool {
+        address < 0x8000 || (0xA000..0xC000).contains(&address)
+    }
+
+    fn read_byte(&self, address: u16) -> u8 {
+        if address < 0x4000 {
+            return self.rom_banks[0][address as usize];
+        }
+
+        if (0x4000..0x8000).contains(&address) {
+            let bank = self.rom_bank % self.rom_banks.len();
+            return self.rom_banks[bank][(address - 0x4000) as usize];
+        }
+
+        if (0xA000..0xC000).contains(&address) {
+            return match self.mode {
+                MODE_RAM_READ_ONLY | MODE_RAM if self.ram_size > 0 => {
+                    self.ram_banks[self.ram_bank][(address - 0xA000) as usize]
+                }
+                MODE_RTC_RESPONSE => (self.pending_command & 0x70) | self.response,
+                // BIT 0 IS SET WHILE THE CLOCK IS READY FOR THE NEXT COMMAND
+                MODE_RTC_SEMAPHORE => 0xFF,
+                // BIT 0 IS SET WHILE THE RECEIVER SEES LIGHT
+                MODE_IR => 0xC0 | self.infrared.is_receiving() as u8,
+                _ => 0xFF,
+            };
+        }
+
+        0xFF
+    }
+
+    fn write_byte(&mut self, address: u16, value: u8) {
+        if address < 0x2000 {
+            // SELECT WHAT 0xA000-0xBFFF MAPS TO
+            self.mode = value & 0xF;
+        } else if (0x2000..0x4000).contains(&address) {
+            // SELECT ROM BANK NUMBER
+            self.rom_bank = (value & 0b0111_1111) as usize;
+        } else if (0x4000..0x6000).contains(&address) {
+            // SELECT RAM BANK NUMBER
+            self.ram_bank = (value & 0b11) as usize;
+        } else if (0xA000..0xC000).contains(&address) {
+            match self.mode {
+                MODE_RAM if self.ram_size > 0 => {
+                    self.ram_banks[self.ram_bank][(address - 0xA000) as usize] = value;
+                }
+                MODE_RTC_COMMAND => self.pending_command = value & 0x7F,
+                // CLEARING THE SEMAPHORE STARTS THE COMMAND
+                MODE_RTC_SEMAPHORE if value & 0b1 == 0 => self.execute_command(),
+                // BIT 0 SWITCHES THE LED
+                MODE_IR => self.infrared.set_led(value & 0b1 > 0),
+                _ => {}
+            }
+        }
+    }
+}
+
+impl Display for HuC3 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "HuC3 running game {}", self.get_game_name())
+    }
+}
+
+impl Mbc for HuC3 {
+    fn get_header(&self) -> &CartridgeHeader {
+        &self.header
+    }
+
+    fn get_rom_bank(&self, address: u16) -> usize {
+        if address < 0x4000 {
+            0
+        } else {
+            self.rom_bank % self.rom_banks.len()
+        }
+    }
+
+    fn step(&mut self) {
+        self.clock.step();
+    }
+
+    fn take_speaker_tone(&mut self) -> Option<u8> {
+        self.speaker_tone.take()
+    }
+
+    fn connect_infrared(&mut self, peer: Box<dyn InfraredPeer>) {
+        self.infrared = peer;
+    }
+
+    fn export_battery_ram(&self) -> Vec<u8> {
+        let mut data: Vec<u8> = self
+            .ram_banks
+            .iter()
+            .flatten()
+            .take(self.ram_size)
+            .copied()
+            .collect();
+
+        // THE CLOCK STATE IS APPENDED AS A FOOTER
+        data.extend_from_slice(&self.clock.to_save_footer());
+        data
+    }
+
+    fn import_battery_ram(&mut self, data: &[u8]) {
+        let ram = self.ram_banks.iter_mut().flatten().take(self.ram_size);
+        for (target, source) in ram.zip(data) {
+            *target = *source;
+        }
+
+        if data.len() > self.ram_size {
+            self.clock.load_save_footer(&data[self.ram_size..]);
+        }
+        self.clock.catch_up();
+    }
+}
@@ -0,0 +1,120 @@
+use crate::gameboy::mbc::real_time_clock;
+
+const CLOCKS_PER_SECOND: usize = 4_194_304;
+const SECONDS_PER_MINUTE: u64 = 60;
+const MINUTES_PER_DAY: u64 = 24 * 60;
+
+const SAVE_FOOTER_LENGTH: usize = 16;
+
+/// HuC3 real time clock
+///
+/// The clock only knows the minute of the day and a day counter. Like the MBC3 clock it remembers the host timestamp of its last update,
+/// so `catch_up` can advance it by the time that passed while the emulator was not running.
+pub struct HuC3Clock {
+    minutes: u16,
+    days: u16,
+    seconds: u64,
+    clock_accumulator: usize,
+    timestamp: u64,
+}
+
+impl HuC3Clock {
+    pub fn new() -> Self {
+        Self {
+            minutes: 0,
+            days: 0,
+            seconds: 0,
+            clock_accumulator: 0,
+            timestamp: real_time_clock::host_timestamp(),
+        }
+    }
+
+    pub fn step(&mut self) {
+        self.clock_accumulator += 4;
+        if self.clock_accumulator >= CLOCKS_PER_SECOND {
+            self.clock_accumulator -= CLOCKS_PER_SECOND;
+            self.advance_seconds(1);
+            self.timestamp = real_time_clock::host_timestamp();
+        }
+    }
+
+    /// Advances the clock by the host time that passed since the last update.
+    pub fn catch_up(&mut self) {
+        let now = real_time_clock::host_timestamp();
+        if now > self.timestamp {
+            self.advance_seconds(now - self.timestamp);
+        }
+        self.timestamp = now;
+    }
+
+    fn advance_seconds(&mut self, seconds: u64) {
+        self.seconds += seconds;
+        let minutes = self.minutes as u64 + self.seconds / SECONDS_PER_MINUTE;
+        self.seconds %= SECONDS_PER_MINUTE;
+
+        // THE DAY COUNTER HAS 16 BITS AND WRAPS AROUND
+        self.days = (self.days as u64 + minutes / MINUTES_PER_DAY) as u16;
+        self.minutes = (minutes % MINUTES_PER_DAY) as u16;
+    }
+
+    /// Stores the time in the layout the HuC3 uses in its nibble memory: 3 nibbles minute of the day followed by 4 nibbles day counter,
+    /// least significant nibble first.
+    pub fn to_nibbles(&self) -> [u8; 7] {
+        let mut nibbles = [0; 7];
+        for (index, nibble) in nibbles.iter_mut().enumerate() {
+            *nibble = if index < 3 {
+                (self.minutes >> (index * 4)) as u8 & 0xF
+            } else {
+                (self.days >> ((index - 3) * 4)) as u8 & 0xF
+            };
+        }
+        nibbles
+    }
+
+    pub fn set_from_nibbles(&mut self, nibbles: &[u8; 7]) {
+        let minutes = nibbles[0..3]
+            .iter()
+            .rev()
+            .fold(0u16, |value, &nibble| (value << 4) | nibble as u16);
+        self.minutes = minutes % MINUTES_PER_DAY as u16;
+        self.days = nibbles[3..7]
+            .iter()
+            .rev()
+            .fold(0u16, |value, &nibble| (value << 4) | nibble as u16);
+        self.seconds = 0;
+        self.clock_accumulator = 0;
+    }
+
+    /// Serializes the clock into a 16 byte save file footer: minute of the day and day counter as 32 bit little endian values, followed by
+    /// a 64 bit unix timestamp.
+    pub fn to_save_footer(&self) -> Vec<u8> {
+        let mut footer = Vec::with_capacity(SAVE_FOOTER_LENGTH);
+        footer.extend_from_slice(&(self.minutes as u32).to_le_bytes());
+        footer.extend_from_slice(&(self.days as u32).to_le_bytes());
+        footer.extend_from_slice(&self.timestamp.to_le_bytes());
+        footer
+    }
+
+    pub fn load_save_footer(&mut self, footer: &[u8]) {
+        if footer.len() < SAVE_FOOTER_LENGTH {
+            return;
+        }
+
+        let read_u32 = |offset: usize| {
+            u32::from_le_bytes([
+                footer[offset],
+                footer[offset + 1],
+                footer[offset + 2],
+                footer[offset + 3],
+            ])
+        };
+        self.minutes = (read_u32(0) as u64 % MINUTES_PER_DAY) as u16;
+        self.days = read_u32(4) as u16;
+        self.seconds = 0;
+        self.clock_accumulator = 0;
+
+        let mut timestamp = [0u8; 8];
+        timestamp.copy_from_slice(&footer[8..16]);
+        self.timestamp = u64::from_le_bytes(timestamp);
+    }
+}
@@ -0,0 +1,55 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// Host side of a cartridge's infrared LED and receiver. The cartridge reports its LED state and asks whether light from the other side
+/// currently hits its receiver.
+pub trait InfraredPeer {
+    fn set_led(&mut self, on: bool);
+
+    fn is_receiving(&self) -> bool;
+}
+
+/// Infrared port that nothing points at, the receiver never sees any light.
+pub struct NoInfraredPeer;
+
+impl InfraredPeer for NoInfraredPeer {
+    fn set_led(&mut self, _on: bool) {}
+
+    fn is_receiving(&self) -> bool {
+        false
+    }
+}
+
+/// One end of an infrared connection between two emulator instances in the same process. The instances may run on different threads.
+pub struct LocalInfraredPeer {
+    own_led: Arc<AtomicBool>,
+    other_led: Arc<AtomicBool>,
+}
+
+impl LocalInfraredPeer {
+    /// Creates two ends that see each other's LED.
+    pub fn pair() -> (Self, Self) {
+        let first_led = Arc::new(AtomicBool::new(false));
+        let second_led = Arc::new(AtomicBool::new(false));
+        (
+            Self {
+                own_led: Arc::clone(&first_led),
+                other_led: Arc::clone(&second_led),
+            },
+            Self {
+                own_led: second_led,
+                other_led: first_led,
+            },
+        )
+    }
+}
+
+impl InfraredPeer for LocalInfraredPeer {
+    fn set_led(&mut self, on: bool) {
+        self.own_led.store(on, Ordering::Relaxed);
+    }
+
+    fn is_receiving(&self) -> bool {
+        self.other_led.load(Ordering::Relaxed)
+    }
+}
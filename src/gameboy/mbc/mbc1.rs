@@ -1,158 +1,204 @@
 use std::cmp::min;
 use std::fmt::{Display, Formatter};
 
+use crate::gameboy::mbc::cartridge_header;
+use crate::gameboy::mbc::cartridge_header::CartridgeHeader;
 use crate::gameboy::mbc::mbc;
 use crate::gameboy::mbc::mbc::Mbc;
-use crate::gameboy::memory::memory;
 use crate::gameboy::memory::memory::Memory;
 
+const ROM_BANK_SIZE: usize = 0x4000;
+const RAM_BANK_SIZE: usize = 0x2000;
+
 pub struct Mbc1 {
-    rom_banks: Box<[[u8; 128]; 0x4000]>,
-    ram_banks: Box<[[u8; 4]; 0x2000]>,
+    rom_banks: Vec<[u8; ROM_BANK_SIZE]>,
+    ram_banks: Box<[[u8; RAM_BANK_SIZE]; 4]>,
+    ram_size: usize,
     mode: Mode,
-    bank_select_register: usize,
+    bank1_register: u8,
+    bank2_register: u8,
     ram_enabled: bool,
-    booted: bool,
-    rom_bank0: Box<[u8; 0x4000]>,
-    ff50_register: u8,
+    multicart: bool,
+    header: CartridgeHeader,
 }
 
 impl Mbc1 {
-    pub fn new(cartridge_data: &[u8]) -> Self {
+    pub fn new(cartridge_data: &[u8], header: CartridgeHeader) -> Self {
+        let bank_count = header.rom_bank_count().clamp(2, 128);
         let mut result = Self {
-            rom_banks: Box::new([[0; 128]; 0x4000]),
-            ram_banks: Box::new([[0; 4]; 0x2000]),
+            rom_banks: vec![[0; ROM_BANK_SIZE]; bank_count],
+            ram_banks: Box::new([[0; RAM_BANK_SIZE]; 4]),
+            ram_size: 0,
             mode: Mode::Rom,
-            bank_select_register: 1,
+            bank1_register: 1,
+            bank2_register: 0,
             ram_enabled: false,
-            booted: false,
-            rom_bank0: Box::new([0; 0x4000]),
-            ff50_register: 0xFF,
+            multicart: false,
+            header,
         };
 
-        // COPY ROM BANK 0
-        let rom_length = min(result.rom_bank0.len(), cartridge_data.len());
-        result.rom_bank0[0..rom_length].copy_from_slice(&cartridge_data[0..rom_length]);
-
-        // COPY OTHER ROM BANKS
-        let mut remaining = cartridge_data.len() - rom_length;
-        let mut bank_index = 0;
-        while remaining > 0 {
-            let length = min(remaining, 0x4000);
-            let start_index = cartridge_data.len() - remaining;
-            let end_index = start_index + length;
-            result.rom_banks[bank_index][0..length]
-                .copy_from_slice(&cartridge_data[start_index..end_index]);
-            remaining -= length;
-            bank_index += 1;
+        // COPY ROM BANKS
+        for (bank_index, bank_data) in cartridge_data
+            .chunks(ROM_BANK_SIZE)
+            .take(bank_count)
+            .enumerate()
+        {
+            result.rom_banks[bank_index][0..bank_data.len()].copy_from_slice(bank_data);
         }
+        result.ram_size = min(
+            result.header.ram_size,
+            RAM_BANK_SIZE * result.ram_banks.len(),
+        );
+        result.multicart = result.is_multicart();
 
         result
     }
+
+    /// MBC1M multicarts are 1 MiB cartridges that contain several games of 256 KiB each. Every game starts with its own header, so
+    /// a repeated Nintendo logo at the start of the 16th, 32nd or 48th bank gives them away.
+    fn is_multicart(&self) -> bool {
+        if self.rom_banks.len() != 64 {
+            return false;
+        }
+
+        let logo_range = cartridge_header::LOGO..cartridge_header::LOGO + mbc::NINTENDO_LOGO.len();
+        let logo_count = [0x10, 0x20, 0x30]
+            .iter()
+            .filter(|&&bank| self.rom_banks[bank][logo_range.clone()] == mbc::NINTENDO_LOGO)
+            .count();
+        logo_count > 1
+    }
+
+    /// Multicarts don't connect bit 4 of the BANK1 register, BANK2 is shifted into bits 4 and 5 instead.
+    fn bank2_shift(&self) -> u8 {
+        if self.multicart {
+            4
+        } else {
+            5
+        }
+    }
+
+    fn low_rom_bank(&self) -> usize {
+        let bank = match self.mode {
+            Mode::Rom => 0,
+            Mode::Ram => (self.bank2_register << self.bank2_shift()) as usize,
+        };
+        bank % self.rom_banks.len()
+    }
+
+    fn high_rom_bank(&self) -> usize {
+        let bank1_mask = if self.multicart { 0b1111 } else { 0b11111 };
+        let bank = (self.bank2_register << self.bank2_shift()) | (self.bank1_register & bank1_mask);
+        bank as usize % self.rom_banks.len()
+    }
+
+    fn ram_bank(&self) -> usize {
+        // SMALLER RAM CHIPS ONLY DECODE THE LOWER ADDRESS BITS
+        let bank_count = (self.ram_size / RAM_BANK_SIZE).max(1);
+        match self.mode {
+            Mode::Rom => 0,
+            Mode::Ram => self.bank2_register as usize % bank_count,
+        }
+    }
+
+    fn ram_offset(&self, address: u16) -> usize {
+        let offset = (address - 0xA000) as usize;
+        if self.ram_size < RAM_BANK_SIZE {
+            return offset % self.ram_size.max(1);
+        }
+        offset
+    }
 }
 
 impl Memory for Mbc1 {
     fn accepts_address(&self, address: u16) -> bool {
-        address < 0x8000
-            || address == memory::DISABLE_BOOT_ROM
-            || (0xA000..0xC000).contains(&address)
+        address < 0x8000 || (0xA000..0xC000).contains(&address)
     }
 
     fn read_byte(&self, address: u16) -> u8 {
-        if !self.booted && address <= 0x00FF {
-            return mbc::BOOT_ROM[address as usize];
+        if address < 0x4000 {
+            return self.rom_banks[self.low_rom_bank()][address as usize];
         }
 
         if (0x4000..0x8000).contains(&address) {
-            return match self.mode {
-                Mode::Rom => {
-                    self.rom_banks[self.bank_select_register - 1][(address - 0x4000) as usize]
-                }
-                Mode::Ram => {
-                    self.rom_banks[(self.bank_select_register & 0b11111) - 1]
-                        [(address - 0x4000) as usize]
-                }
-            };
+            return self.rom_banks[self.high_rom_bank()][(address - 0x4000) as usize];
         }
 
         if (0xA000..0xC000).contains(&address) {
-            if self.ram_enabled && self.mode == Mode::Ram {
-                return self.ram_banks[(self.bank_select_register & 0b1111111) >> 5]
-                    [(address - 0xA000) as usize];
+            if !self.ram_enabled || self.ram_size == 0 {
+                return 0xFF;
             }
-            return self.ram_banks[0][(address - 0xA000) as usize];
+            return self.ram_banks[self.ram_bank()][self.ram_offset(address)];
         }
 
-        if address == memory::DISABLE_BOOT_ROM {
-            return self.ff50_register;
-        }
-
-        self.rom_bank0[address as usize]
+        0xFF
     }
 
-    fn write_byte(&mut self, address: u16, mut value: u8) {
+    fn write_byte(&mut self, address: u16, value: u8) {
         if address < 0x2000 {
             // ENABLE/DISABLE RAM
-            value &= 0xF;
-            self.ram_enabled = value == 0x0A;
+            self.ram_enabled = (value & 0xF) == 0x0A;
         } else if (0x2000..0x4000).contains(&address) {
-            // SELECT ROM BANK NUMBER (LOWER 5 BITS)
-            value &= 0b11111;
-            if value == 0 {
-                value = 1;
-            }
-            self.bank_select_register = self.bank_select_register & 0b1100000 | (value as usize);
+            // SELECT ROM BANK NUMBER (LOWER 5 BITS), THE ZERO CHECK SEES ALL 5 BITS EVEN ON MULTICARTS
+            let bank = value & 0b11111;
+            self.bank1_register = if bank == 0 { 1 } else { bank };
         } else if (0x4000..0x6000).contains(&address) {
             // SELECT RAM BANK NUMBER OR UPPER BITS OF ROM BANK NUMBER
-            self.bank_select_register =
-                self.bank_select_register & 0b11111 | ((value as usize) & 0b11) << 5;
-            if self.bank_select_register == 0 {
-                self.bank_select_register = 1;
-            }
+            self.bank2_register = value & 0b11;
         } else if (0x6000..0x8000).contains(&address) {
             // SET MODE
-            if (value & 0b11) == 0 {
-                self.mode = Mode::Rom;
-            } else if (value & 0b11) == 1 {
-                self.mode = Mode::Ram;
-            }
+            self.mode = if (value & 0b1) == 0 {
+                Mode::Rom
+            } else {
+                Mode::Ram
+            };
         } else if (0xA000..0xC000).contains(&address) {
             // WRITE TO EXTERNAL RAM
-            if self.mode == Mode::Ram {
-                self.ram_banks[(self.bank_select_register & 0b1111111) >> 5]
-                    [(address as usize) - 0xA000] = value;
-            } else {
-                self.ram_banks[0][(address as usize) - 0xA000] = value;
-            }
-        } else if address == memory::DISABLE_BOOT_ROM {
-            // DISABLE BOOT ROM
-            self.ff50_register = value;
-            if value > 0 {
-                self.booted = true;
+            if self.ram_enabled && self.ram_size > 0 {
+                let bank = self.ram_bank();
+                let offset = self.ram_offset(address);
+                self.ram_banks[bank][offset] = value;
             }
-        } else {
-            self.rom_bank0[address as usize] = value;
         }
     }
 }
 
 impl Display for Mbc1 {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.multicart {
+            return write!(f, "Mbc1 multicart running game {}", self.get_game_name());
+        }
         write!(f, "Mbc1 running game {}", self.get_game_name())
     }
 }
 
 impl Mbc for Mbc1 {
-    fn set_booted(&mut self) {
-        self.booted = true;
+    fn get_header(&self) -> &CartridgeHeader {
+        &self.header
     }
 
-    fn is_booted(&self) -> bool {
-        self.booted
+    fn get_rom_bank(&self, address: u16) -> usize {
+        if address < 0x4000 {
+            self.low_rom_bank()
+        } else {
+            self.high_rom_bank()
+        }
+    }
+
+    fn export_battery_ram(&self) -> Vec<u8> {
+        self.ram_banks
+            .iter()
+            .flatten()
+            .take(self.ram_size)
+            .copied()
+            .collect()
     }
 
-    fn get_game_name(&self) -> String {
-        String::from_utf8_lossy(&self.rom_bank0[0x134..=0x0143]).into()
+    fn import_battery_ram(&mut self, data: &[u8]) {
+        let ram = self.ram_banks.iter_mut().flatten().take(self.ram_size);
+        for (target, source) in ram.zip(data) {
+            *target = *source;
+        }
     }
 }
 
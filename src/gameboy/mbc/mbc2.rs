@@ -0,0 +1,117 @@
+use std::fmt::{Display, Formatter};
+
+use crate::gameboy::mbc::cartridge_header::CartridgeHeader;
+use crate::gameboy::mbc::mbc::Mbc;
+use crate::gameboy::memory::memory::Memory;
+
+const ROM_BANK_SIZE: usize = 0x4000;
+
+/// MBC2 has 512 half-bytes of RAM built into the controller
+const RAM_SIZE: usize = 0x200;
+
+pub struct Mbc2 {
+    rom_banks: Box<[[u8; ROM_BANK_SIZE]; 16]>,
+    ram: [u8; RAM_SIZE],
+    rom_bank_count: usize,
+    rom_bank: usize,
+    ram_enabled: bool,
+    header: CartridgeHeader,
+}
+
+impl Mbc2 {
+    pub fn new(cartridge_data: &[u8], header: CartridgeHeader) -> Self {
+        let mut result = Self {
+            rom_banks: Box::new([[0; ROM_BANK_SIZE]; 16]),
+            ram: [0; RAM_SIZE],
+            rom_bank_count: header.rom_bank_count().clamp(1, 16),
+            rom_bank: 1,
+            ram_enabled: false,
+            header,
+        };
+
+        // COPY ROM BANKS
+        for (bank_index, bank_data) in cartridge_data
+            .chunks(ROM_BANK_SIZE)
+            .take(result.rom_banks.len())
+            .enumerate()
+        {
+            result.rom_banks[bank_index][0..bank_data.len()].copy_from_slice(bank_data);
+        }
+
+        result
+    }
+}
+
+impl Memory for Mbc2 {
+    fn accepts_address(&self, address: u16) -> bool {
+        address < 0x8000 || (0xA000..0xC000).contains(&address)
+    }
+
+    fn read_byte(&self, address: u16) -> u8 {
+        if address < 0x4000 {
+            return self.rom_banks[0][address as usize];
+        }
+
+        if (0x4000..0x8000).contains(&address) {
+            let bank = self.rom_bank % self.rom_bank_count;
+            return self.rom_banks[bank][(address - 0x4000) as usize];
+        }
+
+        if (0xA000..0xC000).contains(&address) {
+            if !self.ram_enabled {
+                return 0xFF;
+            }
+            // ONLY THE LOWER 9 ADDRESS BITS ARE DECODED AND THE UPPER NIBBLE IS OPEN BUS
+            return self.ram[(address as usize) & (RAM_SIZE - 1)] | 0xF0;
+        }
+
+        0xFF
+    }
+
+    fn write_byte(&mut self, address: u16, value: u8) {
+        if address < 0x4000 {
+            // ADDRESS BIT 8 SELECTS BETWEEN RAM ENABLE AND ROM BANK NUMBER
+            if address & 0x100 == 0 {
+                self.ram_enabled = (value & 0xF) == 0x0A;
+            } else {
+                let bank = (value & 0xF) as usize;
+                self.rom_bank = if bank == 0 { 1 } else { bank };
+            }
+        } else if (0xA000..0xC000).contains(&address) {
+            // WRITE TO BUILT-IN RAM
+            if self.ram_enabled {
+                self.ram[(address as usize) & (RAM_SIZE - 1)] = value & 0xF;
+            }
+        }
+    }
+}
+
+impl Display for Mbc2 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Mbc2 running game {}", self.get_game_name())
+    }
+}
+
+impl Mbc for Mbc2 {
+    fn get_header(&self) -> &CartridgeHeader {
+        &self.header
+    }
+
+    fn get_rom_bank(&self, address: u16) -> usize {
+        if address < 0x4000 {
+            0
+        } else {
+            self.rom_bank % self.rom_bank_count
+        }
+    }
+
+    fn export_battery_ram(&self) -> Vec<u8> {
+        self.ram.to_vec()
+    }
+
+    fn import_battery_ram(&mut self, data: &[u8]) {
+        for (target, source) in self.ram.iter_mut().zip(data) {
+            *target = *source & 0xF;
+        }
+    }
+}
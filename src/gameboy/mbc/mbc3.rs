@@ -0,0 +1,181 @@
+use std::cmp::min;
+use std::fmt::{Display, Formatter};
+
+use crate::gameboy::mbc::cartridge_header::CartridgeHeader;
+use crate::gameboy::mbc::mbc::Mbc;
+use crate::gameboy::mbc::real_time_clock::RealTimeClock;
+use crate::gameboy::memory::memory::Memory;
+
+const ROM_BANK_SIZE: usize = 0x4000;
+const RAM_BANK_SIZE: usize = 0x2000;
+
+pub struct Mbc3 {
+    rom_banks: Box<[[u8; ROM_BANK_SIZE]; 128]>,
+    ram_banks: Box<[[u8; RAM_BANK_SIZE]; 4]>,
+    ram_size: usize,
+    rom_bank_count: usize,
+    rom_bank: usize,
+    ram_bank: u8,
+    ram_enabled: bool,
+    rtc: Option<RealTimeClock>,
+    header: CartridgeHeader,
+}
+
+impl Mbc3 {
+    pub fn new(cartridge_data: &[u8], header: CartridgeHeader, has_timer: bool) -> Self {
+        let mut result = Self {
+            rom_banks: Box::new([[0; ROM_BANK_SIZE]; 128]),
+            ram_banks: Box::new([[0; RAM_BANK_SIZE]; 4]),
+            ram_size: 0,
+            rom_bank_count: header.rom_bank_count().clamp(1, 128),
+            rom_bank: 1,
+            ram_bank: 0,
+            ram_enabled: false,
+            rtc: if has_timer {
+                Some(RealTimeClock::new())
+            } else {
+                None
+            },
+            header,
+        };
+
+        // COPY ROM BANKS
+        for (bank_index, bank_data) in cartridge_data
+            .chunks(ROM_BANK_SIZE)
+            .take(result.rom_banks.len())
+            .enumerate()
+        {
+            result.rom_banks[bank_index][0..bank_data.len()].copy_from_slice(bank_data);
+        }
+        result.ram_size = min(
+            result.header.ram_size,
+            RAM_BANK_SIZE * result.ram_banks.len(),
+        );
+
+        result
+    }
+
+    fn mapped_rom_bank(&self) -> usize {
+        // UNUSED BANK BITS ARE NOT CONNECTED ON SMALLER CARTRIDGES
+        self.rom_bank % self.rom_bank_count
+    }
+}
+
+impl Memory for Mbc3 {
+    fn accepts_address(&self, address: u16) -> bool {
+        address < 0x8000 || (0xA000..0xC000).contains(&address)
+    }
+
+    fn read_byte(&self, address: u16) -> u8 {
+        if address < 0x4000 {
+            return self.rom_banks[0][address as usize];
+        }
+
+        if (0x4000..0x8000).contains(&address) {
+            return self.rom_banks[self.mapped_rom_bank()][(address - 0x4000) as usize];
+        }
+
+        if (0xA000..0xC000).contains(&address) {
+            if !self.ram_enabled {
+                return 0xFF;
+            }
+            return match (self.ram_bank, &self.rtc) {
+                (0x00..=0x03, _) => {
+                    self.ram_banks[self.ram_bank as usize][(address - 0xA000) as usize]
+                }
+                (_, Some(rtc)) => rtc.read_register(self.ram_bank),
+                _ => 0xFF,
+            };
+        }
+
+        0xFF
+    }
+
+    fn write_byte(&mut self, address: u16, value: u8) {
+        if address < 0x2000 {
+            // ENABLE/DISABLE RAM AND RTC REGISTERS
+            self.ram_enabled = (value & 0xF) == 0x0A;
+        } else if (0x2000..0x4000).contains(&address) {
+            // SELECT ROM BANK NUMBER
+            let bank = (value & 0b1111111) as usize;
+            self.rom_bank = if bank == 0 { 1 } else { bank };
+        } else if (0x4000..0x6000).contains(&address) {
+            // SELECT RAM BANK NUMBER OR RTC REGISTER
+            self.ram_bank = value;
+        } else if (0x6000..0x8000).contains(&address) {
+            // LATCH CLOCK DATA
+            if let Some(rtc) = self.rtc.as_mut() {
+                rtc.write_latch(value);
+            }
+        } else if (0xA000..0xC000).contains(&address) {
+            // WRITE TO EXTERNAL RAM OR RTC REGISTER
+            if !self.ram_enabled {
+                return;
+            }
+            match (self.ram_bank, self.rtc.as_mut()) {
+                (0x00..=0x03, _) => {
+                    self.ram_banks[self.ram_bank as usize][(address - 0xA000) as usize] = value;
+                }
+                (register, Some(rtc)) => rtc.write_register(register, value),
+                _ => {}
+            }
+        }
+    }
+}
+
+impl Display for Mbc3 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Mbc3 running game {}", self.get_game_name())
+    }
+}
+
+impl Mbc for Mbc3 {
+    fn get_header(&self) -> &CartridgeHeader {
+        &self.header
+    }
+
+    fn get_rom_bank(&self, address: u16) -> usize {
+        if address < 0x4000 {
+            0
+        } else {
+            self.mapped_rom_bank()
+        }
+    }
+
+    fn step(&mut self) {
+        if let Some(rtc) = self.rtc.as_mut() {
+            rtc.step();
+        }
+    }
+
+    fn export_battery_ram(&self) -> Vec<u8> {
+        let mut data: Vec<u8> = self
+            .ram_banks
+            .iter()
+            .flatten()
+            .take(self.ram_size)
+            .copied()
+            .collect();
+
+        // THE CLOCK STATE IS APPENDED AS A FOOTER
+        if let Some(rtc) = self.rtc.as_ref() {
+            data.extend_from_slice(&rtc.to_save_footer());
+        }
+
+        data
+    }
+
+    fn import_battery_ram(&mut self, data: &[u8]) {
+        let ram = self.ram_banks.iter_mut().flatten().take(self.ram_size);
+        for (target, source) in ram.zip(data) {
+            *target = *source;
+        }
+
+        if let Some(rtc) = self.rtc.as_mut() {
+            if data.len() > self.ram_size {
+                rtc.load_save_footer(&data[self.ram_size..]);
+            }
+            rtc.catch_up();
+        }
+    }
+}
@@ -0,0 +1,149 @@
+use std::cmp::min;
+use std::fmt::{Display, Formatter};
+
+use crate::gameboy::mbc::cartridge_header::CartridgeHeader;
+use crate::gameboy::mbc::mbc::Mbc;
+use crate::gameboy::memory::memory::Memory;
+
+const ROM_BANK_SIZE: usize = 0x4000;
+const RAM_BANK_SIZE: usize = 0x2000;
+
+/// Bit 3 of the RAM bank register drives the rumble motor on rumble cartridges
+const RUMBLE_MOTOR_BIT: u8 = 0b1000;
+
+pub struct Mbc5 {
+    rom_banks: Vec<[u8; ROM_BANK_SIZE]>,
+    ram_banks: Box<[[u8; RAM_BANK_SIZE]; 16]>,
+    ram_size: usize,
+    rom_bank: usize,
+    ram_bank: usize,
+    ram_enabled: bool,
+    has_rumble: bool,
+    rumble_active: bool,
+    header: CartridgeHeader,
+}
+
+impl Mbc5 {
+    pub fn new(cartridge_data: &[u8], header: CartridgeHeader, has_rumble: bool) -> Self {
+        let bank_count = header.rom_bank_count().clamp(2, 512);
+        let mut result = Self {
+            rom_banks: vec![[0; ROM_BANK_SIZE]; bank_count],
+            ram_banks: Box::new([[0; RAM_BANK_SIZE]; 16]),
+            ram_size: 0,
+            rom_bank: 1,
+            ram_bank: 0,
+            ram_enabled: false,
+            has_rumble,
+            rumble_active: false,
+            header,
+        };
+
+        // COPY ROM BANKS
+        for (bank_index, bank_data) in cartridge_data
+            .chunks(ROM_BANK_SIZE)
+            .take(bank_count)
+            .enumerate()
+        {
+            result.rom_banks[bank_index][0..bank_data.len()].copy_from_slice(bank_data);
+        }
+        result.ram_size = min(
+            result.header.ram_size,
+            RAM_BANK_SIZE * result.ram_banks.len(),
+        );
+
+        result
+    }
+}
+
+impl Memory for Mbc5 {
+    fn accepts_address(&self, address: u16) -> bool {
+        address < 0x8000 || (0xA000..0xC000).contains(&address)
+    }
+
+    fn read_byte(&self, address: u16) -> u8 {
+        if address < 0x4000 {
+            return self.rom_banks[0][address as usize];
+        }
+
+        if (0x4000..0x8000).contains(&address) {
+            // UNUSED BANK BITS ARE NOT CONNECTED ON SMALLER CARTRIDGES, BANK 0 IS A VALID SELECTION
+            let bank = self.rom_bank % self.rom_banks.len();
+            return self.rom_banks[bank][(address - 0x4000) as usize];
+        }
+
+        if (0xA000..0xC000).contains(&address) {
+            if !self.ram_enabled {
+                return 0xFF;
+            }
+            return self.ram_banks[self.ram_bank][(address - 0xA000) as usize];
+        }
+
+        0xFF
+    }
+
+    fn write_byte(&mut self, address: u16, value: u8) {
+        if address < 0x2000 {
+            // ENABLE/DISABLE RAM
+            self.ram_enabled = value == 0x0A;
+        } else if (0x2000..0x3000).contains(&address) {
+            // SELECT ROM BANK NUMBER (LOWER 8 BITS)
+            self.rom_bank = (self.rom_bank & 0x100) | value as usize;
+        } else if (0x3000..0x4000).contains(&address) {
+            // SELECT ROM BANK NUMBER (9TH BIT)
+            self.rom_bank = (self.rom_bank & 0xFF) | (((value & 0b1) as usize) << 8);
+        } else if (0x4000..0x6000).contains(&address) {
+            // SELECT RAM BANK NUMBER AND RUMBLE MOTOR
+            if self.has_rumble {
+                self.rumble_active = value & RUMBLE_MOTOR_BIT > 0;
+                self.ram_bank = (value & 0b0111) as usize;
+            } else {
+                self.ram_bank = (value & 0b1111) as usize;
+            }
+        } else if (0xA000..0xC000).contains(&address) {
+            // WRITE TO EXTERNAL RAM
+            if self.ram_enabled {
+                self.ram_banks[self.ram_bank][(address - 0xA000) as usize] = value;
+            }
+        }
+    }
+}
+
+impl Display for Mbc5 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Mbc5 running game {}", self.get_game_name())
+    }
+}
+
+impl Mbc for Mbc5 {
+    fn get_header(&self) -> &CartridgeHeader {
+        &self.header
+    }
+
+    fn get_rom_bank(&self, address: u16) -> usize {
+        if address < 0x4000 {
+            0
+        } else {
+            self.rom_bank % self.rom_banks.len()
+        }
+    }
+
+    fn is_rumble_active(&self) -> bool {
+        self.rumble_active
+    }
+
+    fn export_battery_ram(&self) -> Vec<u8> {
+        self.ram_banks
+            .iter()
+            .flatten()
+            .take(self.ram_size)
+            .copied()
+            .collect()
+    }
+
+    fn import_battery_ram(&mut self, data: &[u8]) {
+        let ram = self.ram_banks.iter_mut().flatten().take(self.ram_size);
+        for (target, source) in ram.zip(data) {
+            *target = *source;
+        }
+    }
+}
@@ -0,0 +1,165 @@
+use std::fmt::{Display, Formatter};
+
+use crate::gameboy::mbc::cartridge_header::CartridgeHeader;
+use crate::gameboy::mbc::mbc::Mbc;
+use crate::gameboy::mbc::serial_eeprom::SerialEeprom;
+use crate::gameboy::memory::memory::Memory;
+
+const ROM_BANK_SIZE: usize = 0x4000;
+
+/// Accelerometer reading of a cartridge lying flat
+const ACCELEROMETER_CENTER: f32 = 0x81D0 as f32;
+
+/// Change of the accelerometer reading for a tilt of 1g
+const ACCELEROMETER_GRAVITY: f32 = 0x70 as f32;
+
+/// Erasing the latch resets both axes to this value until the next latch
+const ACCELEROMETER_ERASED: u16 = 0x8000;
+
+pub struct Mbc7 {
+    rom_banks: Vec<[u8; ROM_BANK_SIZE]>,
+    rom_bank: usize,
+    ram_enabled_1: bool,
+    ram_enabled_2: bool,
+    eeprom: SerialEeprom,
+    tilt_x: f32,
+    tilt_y: f32,
+    latched_x: u16,
+    latched_y: u16,
+    latch_erased: bool,
+    header: CartridgeHeader,
+}
+
+impl Mbc7 {
+    pub fn new(cartridge_data: &[u8], header: CartridgeHeader) -> Self {
+        let bank_count = header.rom_bank_count().clamp(2, 128);
+        let mut result = Self {
+            rom_banks: vec![[0; ROM_BANK_SIZE]; bank_count],
+            rom_bank: 1,
+            ram_enabled_1: false,
+            ram_enabled_2: false,
+            eeprom: SerialEeprom::new(),
+            tilt_x: 0.0,
+            tilt_y: 0.0,
+            latched_x: ACCELEROMETER_ERASED,
+            latched_y: ACCELEROMETER_ERASED,
+            latch_erased: false,
+            header,
+        };
+
+        // COPY ROM BANKS
+        for (bank_index, bank_data) in cartridge_data
+            .chunks(ROM_BANK_SIZE)
+            .take(bank_count)
+            .enumerate()
+        {
+            result.rom_banks[bank_index][0..bank_data.len()].copy_from_slice(bank_data);
+        }
+
+        result
+    }
+
+    fn is_ram_enabled(&self) -> bool {
+        self.ram_enabled_1 && self.ram_enabled_2
+    }
+
+    fn accelerometer_value(tilt: f32) -> u16 {
+        (ACCELEROMETER_CENTER - tilt * ACCELEROMETER_GRAVITY) as u16
+    }
+}
+
+impl Memory for Mbc7 {
+    fn accepts_address(&self, address: u16) -> bool {
+        address < 0x8000 || (0xA000..0xC000).contains(&address)
+    }
+
+    fn read_byte(&self, address: u16) -> u8 {
+        if address < 0x4000 {
+            return self.rom_banks[0][address as usize];
+        }
+
+        if (0x4000..0x8000).contains(&address) {
+            let bank = self.rom_bank % self.rom_banks.len();
+            return self.rom_banks[bank][(address - 0x4000) as usize];
+        }
+
+        if (0xA000..0xB000).contains(&address) && self.is_ram_enabled() {
+            // THE REGISTER IS SELECTED BY ADDRESS BITS 4-7
+            return match (address >> 4) & 0xF {
+                0x2 => self.latched_x as u8,
+                0x3 => (self.latched_x >> 8) as u8,
+                0x4 => self.latched_y as u8,
+                0x5 => (self.latched_y >> 8) as u8,
+                0x6 => 0x00,
+                0x8 => self.eeprom.read_pins(),
+                _ => 0xFF,
+            };
+        }
+
+        0xFF
+    }
+
+    fn write_byte(&mut self, address: u16, value: u8) {
+        if address < 0x2000 {
+            // ENABLE/DISABLE RAM, PART 1
+            self.ram_enabled_1 = value == 0x0A;
+        } else if (0x2000..0x4000).contains(&address) {
+            // SELECT ROM BANK NUMBER
+            self.rom_bank = (value & 0b0111_1111) as usize;
+        } else if (0x4000..0x6000).contains(&address) {
+            // ENABLE/DISABLE RAM, PART 2
+            self.ram_enabled_2 = value == 0x40;
+        } else if (0xA000..0xB000).contains(&address) && self.is_ram_enabled() {
+            match (address >> 4) & 0xF {
+                // ERASE ACCELEROMETER LATCH
+                0x0 if value == 0x55 => {
+                    self.latched_x = ACCELEROMETER_ERASED;
+                    self.latched_y = ACCELEROMETER_ERASED;
+                    self.latch_erased = true;
+                }
+                // LATCH ACCELEROMETER
+                0x1 if value == 0xAA && self.latch_erased => {
+                    self.latched_x = Self::accelerometer_value(self.tilt_x);
+                    self.latched_y = Self::accelerometer_value(self.tilt_y);
+                    self.latch_erased = false;
+                }
+                // EEPROM PINS
+                0x8 => self.eeprom.write_pins(value),
+                _ => {}
+            }
+        }
+    }
+}
+
+impl Display for Mbc7 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Mbc7 running game {}", self.get_game_name())
+    }
+}
+
+impl Mbc for Mbc7 {
+    fn get_header(&self) -> &CartridgeHeader {
+        &self.header
+    }
+
+    fn get_rom_bank(&self, address: u16) -> usize {
+        if address < 0x4000 {
+            0
+        } else {
+            self.rom_bank % self.rom_banks.len()
+        }
+    }
+
+    fn set_tilt(&mut self, x: f32, y: f32) {
+        self.tilt_x = x.clamp(-1.0, 1.0);
+        self.tilt_y = y.clamp(-1.0, 1.0);
+    }
+
+    fn export_battery_ram(&self) -> Vec<u8> {
+        self.eeprom.export()
+    }
+
+    fn import_battery_ram(&mut self, data: &[u8]) {
+        self.eeprom.import(data);
+    }
+}
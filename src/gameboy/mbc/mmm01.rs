@@ -0,0 +1,250 @@
+use std::cmp::min;
+use std::fmt::{Display, Formatter};
+
+use crate::gameboy::mbc::cartridge_header::CartridgeHeader;
+use crate::gameboy::mbc::mbc::Mbc;
+use crate::gameboy::memory::memory::Memory;
+
+const ROM_BANK_SIZE: usize = 0x4000;
+const RAM_BANK_SIZE: usize = 0x2000;
+
+/// The menu occupies the last 32 KiB of the ROM
+pub const MENU_SIZE: usize = 0x8000;
+
+/// Writing bit 6 to 0x0000-0x1FFF maps the selected game and locks the outer bank registers
+const MAP_ENABLE: u8 = 0b0100_0000;
+
+/// Mapper of multicarts like Momotarou Collection 2 and Taito Variety Pack
+///
+/// The cartridge starts with the menu in the last 32 KiB of the ROM mapped. The menu then sets the outer ROM and RAM bank bits of the
+/// selected game and maps it, from then on the game sees a plain MBC1 whose bank registers can't leave the game's area. The bank mask
+/// registers decide which bits of the bank registers the game is still able to change.
+pub struct Mmm01 {
+    rom_banks: Vec<[u8; ROM_BANK_SIZE]>,
+    ram_banks: Box<[[u8; RAM_BANK_SIZE]; 16]>,
+    ram_size: usize,
+    ram_enabled: bool,
+    mapped: bool,
+    rom_bank_low: u8,
+    rom_bank_mid: u8,
+    rom_bank_high: u8,
+    rom_bank_mask: u8,
+    ram_bank_low: u8,
+    ram_bank_high: u8,
+    ram_bank_mask: u8,
+    mbc1_mode: bool,
+    mbc1_mode_locked: bool,
+    multiplex: bool,
+    header: CartridgeHeader,
+}
+
+impl Mmm01 {
+    pub fn new(cartridge_data: &[u8], header: CartridgeHeader) -> Self {
+        let bank_count = (cartridge_data.len() / ROM_BANK_SIZE).clamp(2, 512);
+        let mut result = Self {
+            rom_banks: vec![[0; ROM_BANK_SIZE]; bank_count],
+            ram_banks: Box::new([[0; RAM_BANK_SIZE]; 16]),
+            ram_size: 0,
+            ram_enabled: false,
+            mapped: false,
+            rom_bank_low: 1,
+            rom_bank_mid: 0,
+            rom_bank_high: 0,
+            rom_bank_mask: 0,
+            ram_bank_low: 0,
+            ram_bank_high: 0,
+            ram_bank_mask: 0,
+            mbc1_mode: false,
+            mbc1_mode_locked: false,
+            multiplex: false,
+            header,
+        };
+
+        // COPY ROM BANKS
+        for (bank_index, bank_data) in cartridge_data
+            .chunks(ROM_BANK_SIZE)
+            .take(bank_count)
+            .enumerate()
+        {
+            result.rom_banks[bank_index][0..bank_data.len()].copy_from_slice(bank_data);
+        }
+        result.ram_size = min(
+            result.header.ram_size,
+            RAM_BANK_SIZE * result.ram_banks.len(),
+        );
+
+        result
+    }
+
+    /// Returns the menu of an MMM01 dump, which holds the header describing the whole cartridge.
+    pub fn find_menu(data: &[u8]) -> Option<&[u8]> {
+        if data.len() <= MENU_SIZE || data.len() % MENU_SIZE != 0 {
+            return None;
+        }
+
+        let menu = &data[data.len() - MENU_SIZE..];
+        let header = CartridgeHeader::parse(menu).ok()?;
+        if (0x0B..=0x0D).contains(&header.cartridge_type) && header.is_logo_valid() {
+            Some(menu)
+        } else {
+            None
+        }
+    }
+
+    fn low_rom_bank(&self) -> usize {
+        // UNMAPPED, ALL OUTER BANK BITS ARE PULLED HIGH SO THE LAST 32 KiB ARE VISIBLE
+        if !self.mapped {
+            return 0x1FE % self.rom_banks.len();
+        }
+
+        let middle = if self.multiplex {
+            if self.mbc1_mode {
+                0
+            } else {
+                self.ram_bank_low
+            }
+        } else {
+            self.rom_bank_mid
+        };
+        let bank = (self.rom_bank_low & (self.rom_bank_mask << 1)) as usize
+            | (middle as usize) << 5
+            | (self.rom_bank_high as usize) << 7;
+        bank % self.rom_banks.len()
+    }
+
+    fn high_rom_bank(&self) -> usize {
+        if !self.mapped {
+            return (0x1FE | (self.rom_bank_low & 0b1) as usize) % self.rom_banks.len();
+        }
+
+        let middle = if self.multiplex {
+            self.ram_bank_low
+        } else {
+            self.rom_bank_mid
+        };
+        let bank = (self.rom_bank_low as usize
+            | (middle as usize) << 5
+            | (self.rom_bank_high as usize) << 7)
+            % self.rom_banks.len();
+
+        // LIKE ON THE MBC1, THE UPPER AREA CAN'T SHOW THE SAME BANK AS THE LOWER AREA
+        if bank == self.low_rom_bank() {
+            return (bank + 1) % self.rom_banks.len();
+        }
+        bank
+    }
+
+    fn ram_bank(&self) -> usize {
+        // IN MULTIPLEX MODE THE RAM AND ROM BANK REGISTERS SWAP THEIR MIDDLE BITS
+        let low = if self.multiplex {
+            self.rom_bank_mid
+        } else {
+            self.ram_bank_low
+        };
+        let bank = low | (self.ram_bank_high << 2);
+        bank as usize % (self.ram_size / RAM_BANK_SIZE).max(1)
+    }
+}
+
+impl Memory for Mmm01 {
+    fn accepts_address(&self, address: u16) -> bool {
+        address < 0x8000 || (0xA000..0xC000).contains(&address)
+    }
+
+    fn read_byte(&self, address: u16) -> u8 {
+        if address < 0x4000 {
+            return self.rom_banks[self.low_rom_bank()][address as usize];
+        }
+
+        if (0x4000..0x8000).contains(&address) {
+            return self.rom_banks[self.high_rom_bank()][(address - 0x4000) as usize];
+        }
+
+        if (0xA000..0xC000).contains(&address) {
+            if !self.ram_enabled || self.ram_size == 0 {
+                return 0xFF;
+            }
+            return self.ram_banks[self.ram_bank()][(address - 0xA000) as usize];
+        }
+
+        0xFF
+    }
+
+    fn write_byte(&mut self, address: u16, value: u8) {
+        if address < 0x2000 {
+            // ENABLE/DISABLE RAM, BEFORE MAPPING ALSO THE RAM BANK MASK AND THE MAP ENABLE BIT
+            self.ram_enabled = (value & 0xF) == 0x0A;
+            if !self.mapped {
+                self.ram_bank_mask = (value >> 4) & 0b11;
+                self.mapped = value & MAP_ENABLE > 0;
+            }
+        } else if (0x2000..0x4000).contains(&address) {
+            // SELECT ROM BANK NUMBER, MASKED BITS KEEP THEIR VALUE
+            if !self.mapped {
+                self.rom_bank_mid = (value >> 5) & 0b11;
+            }
+            let fixed_bits = self.rom_bank_mask << 1;
+            self.rom_bank_low = (self.rom_bank_low & fixed_bits) | (value & !fixed_bits & 0b1_1111);
+        } else if (0x4000..0x6000).contains(&address) {
+            // SELECT RAM BANK NUMBER, BEFORE MAPPING ALSO THE OUTER BANK BITS
+            let fixed_bits = self.ram_bank_mask;
+            self.ram_bank_low = (self.ram_bank_low & fixed_bits) | (value & !fixed_bits & 0b11);
+            if !self.mapped {
+                self.ram_bank_high = (value >> 2) & 0b11;
+                self.rom_bank_high = (value >> 4) & 0b11;
+                self.mbc1_mode_locked = value & 0b0100_0000 > 0;
+            }
+        } else if (0x6000..0x8000).contains(&address) {
+            // SET MODE, BEFORE MAPPING ALSO THE ROM BANK MASK
+            if !self.mbc1_mode_locked {
+                self.mbc1_mode = value & 0b1 > 0;
+            }
+            if !self.mapped {
+                self.rom_bank_mask = (value >> 2) & 0b1111;
+                self.multiplex = value & 0b0100_0000 > 0;
+            }
+        } else if (0xA000..0xC000).contains(&address) {
+            // WRITE TO EXTERNAL RAM
+            if self.ram_enabled && self.ram_size > 0 {
+                let bank = self.ram_bank();
+                self.ram_banks[bank][(address - 0xA000) as usize] = value;
+            }
+        }
+    }
+}
+
+impl Display for Mmm01 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Mmm01 running game {}", self.get_game_name())
+    }
+}
+
+impl Mbc for Mmm01 {
+    fn get_header(&self) -> &CartridgeHeader {
+        &self.header
+    }
+
+    fn get_rom_bank(&self, address: u16) -> usize {
+        if address < 0x4000 {
+            self.low_rom_bank()
+        } else {
+            self.high_rom_bank()
+        }
+    }
+
+    fn export_battery_ram(&self) -> Vec<u8> {
+        self.ram_banks
+            .iter()
+            .flatten()
+            .take(self.ram_size)
+            .copied()
+            .collect()
+    }
+
+    fn import_battery_ram(&mut self, data: &[u8]) {
+        let ram = self.ram_banks.iter_mut().flatten().take(self.ram_size);
+        for (target, source) in ram.zip(data) {
+            *target = *source;
+        }
+    }
+}
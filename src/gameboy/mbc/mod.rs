@@ -1,4 +1,26 @@
+mod bbd;
+pub mod camera_image_source;
+pub mod cartridge_header;
+mod huc1;
+mod huc3;
+mod huc3_clock;
+pub mod infrared;
 pub mod mbc;
 mod mbc0;
 mod mbc1;
+mod mbc2;
+mod mbc3;
+mod mbc5;
+mod mbc7;
+mod mmm01;
+mod pocket_camera;
+mod real_time_clock;
+pub mod rom_archive;
+pub mod rom_error;
 pub mod rom_loader;
+pub mod rom_patch;
+mod sachen;
+mod serial_eeprom;
+mod tama5;
+mod tama5_clock;
+mod wisdom_tree;
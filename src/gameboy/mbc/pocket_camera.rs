@@ -0,0 +1,293 @@
+use std::cmp::min;
+use std::fmt::{Display, Formatter};
+
+use crate::gameboy::mbc::camera_image_source::{
+    CameraImage, CameraImageSource, TestPatternSource, CAMERA_HEIGHT, CAMERA_WIDTH,
+};
+use crate::gameboy::mbc::cartridge_header::CartridgeHeader;
+use crate::gameboy::mbc::mbc::Mbc;
+use crate::gameboy::memory::memory::Memory;
+
+const ROM_BANK_SIZE: usize = 0x4000;
+const RAM_BANK_SIZE: usize = 0x2000;
+
+/// Setting bit 4 of the RAM bank register maps the camera registers instead of RAM
+const CAMERA_REGISTERS_SELECTED: u8 = 0b1_0000;
+
+const REGISTER_COUNT: usize = 0x36;
+const REGISTER_CONTROL: usize = 0x00;
+const REGISTER_EDGE_MODE: usize = 0x01;
+const REGISTER_EXPOSURE_HIGH: usize = 0x02;
+const REGISTER_EXPOSURE_LOW: usize = 0x03;
+const REGISTER_EDGE_RATIO: usize = 0x04;
+const REGISTER_DITHER_MATRIX: usize = 0x06;
+
+/// Bit 0 of the control register starts a capture and stays set until the capture is done
+const CONTROL_CAPTURE: u8 = 0b1;
+
+/// The captured image is written as 16x14 tiles to RAM bank 0 starting at 0xA100
+const IMAGE_RAM_OFFSET: usize = 0x100;
+
+/// Exposure time at which a pixel is reproduced with its original brightness
+const NEUTRAL_EXPOSURE: u32 = 0x0300;
+
+/// Edge enhancement ratio selected by bits 4-6 of register 4
+const EDGE_RATIOS: [f32; 8] = [0.50, 0.75, 1.00, 1.25, 2.00, 3.00, 4.00, 5.00];
+
+/// Game Boy Camera cartridge with the Mitsubishi M64282FP image sensor
+///
+/// The sensor processing is modeled on the processed image only: exposure scales the brightness, the edge enhancement sharpens it and the
+/// dither matrix from the registers turns it into 4 shades of gray. Gain, offset and reference voltage are not emulated.
+pub struct PocketCamera {
+    rom_banks: Vec<[u8; ROM_BANK_SIZE]>,
+    ram_banks: Box<[[u8; RAM_BANK_SIZE]; 16]>,
+    ram_size: usize,
+    rom_bank: usize,
+    ram_bank: u8,
+    ram_enabled: bool,
+    registers: [u8; REGISTER_COUNT],
+    capture_cycles: usize,
+    image_source: Box<dyn CameraImageSource>,
+    header: CartridgeHeader,
+}
+
+impl PocketCamera {
+    pub fn new(cartridge_data: &[u8], header: CartridgeHeader) -> Self {
+        let bank_count = header.rom_bank_count().clamp(2, 64);
+        let mut result = Self {
+            rom_banks: vec![[0; ROM_BANK_SIZE]; bank_count],
+            ram_banks: Box::new([[0; RAM_BANK_SIZE]; 16]),
+            ram_size: 0,
+            rom_bank: 1,
+            ram_bank: 0,
+            ram_enabled: false,
+            registers: [0; REGISTER_COUNT],
+            capture_cycles: 0,
+            image_source: Box::new(TestPatternSource::new()),
+            header,
+        };
+
+        // COPY ROM BANKS
+        for (bank_index, bank_data) in cartridge_data
+            .chunks(ROM_BANK_SIZE)
+            .take(bank_count)
+            .enumerate()
+        {
+            result.rom_banks[bank_index][0..bank_data.len()].copy_from_slice(bank_data);
+        }
+        result.ram_size = min(
+            result.header.ram_size.max(RAM_BANK_SIZE),
+            RAM_BANK_SIZE * result.ram_banks.len(),
+        );
+
+        result
+    }
+
+    fn is_camera_selected(&self) -> bool {
+        self.ram_bank & CAMERA_REGISTERS_SELECTED > 0
+    }
+
+    fn exposure(&self) -> u32 {
+        u16::from_be_bytes([
+            self.registers[REGISTER_EXPOSURE_HIGH],
+            self.registers[REGISTER_EXPOSURE_LOW],
+        ]) as u32
+    }
+
+    /// The capture takes longer with higher exposure times, counted in M-cycles
+    fn capture_duration(&self) -> usize {
+        let edge_exclusive = self.registers[REGISTER_EDGE_MODE] & 0b1000_0000 > 0;
+        32446 + if edge_exclusive { 0 } else { 512 } + 16 * self.exposure() as usize
+    }
+
+    fn finish_capture(&mut self) {
+        let image = self.image_source.capture();
+        let processed = self.process(&image);
+
+        // CONVERT TO 2 BIT TILES, 16 BYTES PER 8x8 TILE
+        let ram = &mut self.ram_banks[0][IMAGE_RAM_OFFSET..];
+        for tile_y in 0..CAMERA_HEIGHT / 8 {
+            for tile_x in 0..CAMERA_WIDTH / 8 {
+                let tile_offset = (tile_y * CAMERA_WIDTH / 8 + tile_x) * 16;
+                for row in 0..8 {
+                    let mut low = 0u8;
+                    let mut high = 0u8;
+                    for column in 0..8 {
+                        let x = tile_x * 8 + column;
+                        let y = tile_y * 8 + row;
+                        let color = processed[y * CAMERA_WIDTH + x];
+                        low |= (color & 0b01) << (7 - column);
+                        high |= ((color & 0b10) >> 1) << (7 - column);
+                    }
+                    ram[tile_offset + row * 2] = low;
+                    ram[tile_offset + row * 2 + 1] = high;
+                }
+            }
+        }
+
+        self.registers[REGISTER_CONTROL] &= !CONTROL_CAPTURE;
+    }
+
+    /// Returns the color number 0-3 of every pixel, 0 is white
+    fn process(&self, image: &CameraImage) -> Vec<u8> {
+        let exposure = self.exposure();
+        let exposed: Vec<f32> = image
+            .iter()
+            .map(|&pixel| (pixel as u32 * exposure / NEUTRAL_EXPOSURE).min(255) as f32)
+            .collect();
+        let pixel = |x: isize, y: isize| {
+            let x = x.clamp(0, CAMERA_WIDTH as isize - 1) as usize;
+            let y = y.clamp(0, CAMERA_HEIGHT as isize - 1) as usize;
+            exposed[y * CAMERA_WIDTH + x]
+        };
+
+        // BITS 5-6 ENABLE HORIZONTAL AND VERTICAL EDGE ENHANCEMENT
+        let edge_mode = (self.registers[REGISTER_EDGE_MODE] >> 5) & 0b11;
+        let edge_ratio = EDGE_RATIOS[((self.registers[REGISTER_EDGE_RATIO] >> 4) & 0b111) as usize];
+        let invert = self.registers[REGISTER_EDGE_RATIO] & 0b1000 > 0;
+
+        let mut colors = Vec::with_capacity(CAMERA_WIDTH * CAMERA_HEIGHT);
+        for y in 0..CAMERA_HEIGHT as isize {
+            for x in 0..CAMERA_WIDTH as isize {
+                let center = pixel(x, y);
+                let horizontal = 2.0 * center - pixel(x - 1, y) - pixel(x + 1, y);
+                let vertical = 2.0 * center - pixel(x, y - 1) - pixel(x, y + 1);
+                let edge = match edge_mode {
+                    0b01 => horizontal,
+                    0b10 => vertical,
+                    0b11 => horizontal + vertical,
+                    _ => 0.0,
+                };
+                let mut value = (center + edge * edge_ratio).clamp(0.0, 255.0) as u8;
+                if invert {
+                    value = 255 - value;
+                }
+
+                // EVERY PIXEL OF A 4x4 BLOCK HAS ITS OWN THREE THRESHOLDS
+                let matrix_offset =
+                    REGISTER_DITHER_MATRIX + ((y as usize % 4) * 4 + (x as usize % 4)) * 3;
+                let thresholds = &self.registers[matrix_offset..matrix_offset + 3];
+                colors.push(if value < thresholds[0] {
+                    3
+                } else if value < thresholds[1] {
+                    2
+                } else if value < thresholds[2] {
+                    1
+                } else {
+                    0
+                });
+            }
+        }
+        colors
+    }
+}
+
+impl Memory for PocketCamera {
+    fn accepts_address(&self, address: u16) -> bool {
+        address < 0x8000 || (0xA000..0xC000).contains(&address)
+    }
+
+    fn read_byte(&self, address: u16) -> u8 {
+        if address < 0x4000 {
+            return self.rom_banks[0][address as usize];
+        }
+
+        if (0x4000..0x8000).contains(&address) {
+            let bank = self.rom_bank % self.rom_banks.len();
+            return self.rom_banks[bank][(address - 0x4000) as usize];
+        }
+
+        if (0xA000..0xC000).contains(&address) {
+            if self.is_camera_selected() {
+                // ONLY THE CONTROL REGISTER CAN BE READ, THE REGISTERS ARE MIRRORED EVERY 0x80 BYTES
+                if (address & 0x7F) as usize == REGISTER_CONTROL {
+                    return self.registers[REGISTER_CONTROL];
+                }
+                return 0x00;
+            }
+            let bank = (self.ram_bank & 0xF) as usize % (self.ram_size / RAM_BANK_SIZE);
+            return self.ram_banks[bank][(address - 0xA000) as usize];
+        }
+
+        0xFF
+    }
+
+    fn write_byte(&mut self, address: u16, value: u8) {
+        if address < 0x2000 {
+            // ENABLE/DISABLE RAM WRITES
+            self.ram_enabled = (value & 0xF) == 0x0A;
+        } else if (0x2000..0x4000).contains(&address) {
+            // SELECT ROM BANK NUMBER
+            self.rom_bank = (value & 0b11_1111) as usize;
+        } else if (0x4000..0x6000).contains(&address) {
+            // SELECT RAM BANK NUMBER OR CAMERA REGISTERS
+            self.ram_bank = value & 0b1_1111;
+        } else if (0xA000..0xC000).contains(&address) {
+            if self.is_camera_selected() {
+                let register = (address & 0x7F) as usize;
+                if register == REGISTER_CONTROL {
+                    let starts_capture = value & CONTROL_CAPTURE > 0
+                        && self.registers[REGISTER_CONTROL] & CONTROL_CAPTURE == 0;
+                    self.registers[REGISTER_CONTROL] = value & 0b111;
+                    if starts_capture {
+                        self.capture_cycles = self.capture_duration();
+                    }
+                } else if register < REGISTER_COUNT {
+                    self.registers[register] = value;
+                }
+            } else if self.ram_enabled {
+                let bank = (self.ram_bank & 0xF) as usize % (self.ram_size / RAM_BANK_SIZE);
+                self.ram_banks[bank][(address - 0xA000) as usize] = value;
+            }
+        }
+    }
+}
+
+impl Display for PocketCamera {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "PocketCamera running game {}", self.get_game_name())
+    }
+}
+
+impl Mbc for PocketCamera {
+    fn get_header(&self) -> &CartridgeHeader {
+        &self.header
+    }
+
+    fn get_rom_bank(&self, address: u16) -> usize {
+        if address < 0x4000 {
+            0
+        } else {
+            self.rom_bank % self.rom_banks.len()
+        }
+    }
+
+    fn step(&mut self) {
+        if self.capture_cycles > 0 {
+            self.capture_cycles -= 1;
+            if self.capture_cycles == 0 {
+                self.finish_capture();
+            }
+        }
+    }
+
+    fn set_camera_image_source(&mut self, source: Box<dyn CameraImageSource>) {
+        self.image_source = source;
+    }
+
+    fn export_battery_ram(&self) -> Vec<u8> {
+        self.ram_banks
+            .iter()
+            .flatten()
+            .take(self.ram_size)
+            .copied()
+            .collect()
+    }
+
+    fn import_battery_ram(&mut self, data: &[u8]) {
+        let ram = self.ram_banks.iter_mut().flatten().take(self.ram_size);
+        for (target, source) in ram.zip(data) {
+            *target = *source;
+        }
+    }
+}
@@ -0,0 +1,246 @@
+use std::cmp::min;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub const RTC_S: u8 = 0x08;
+pub const RTC_M: u8 = 0x09;
+pub const RTC_H: u8 = 0x0A;
+pub const RTC_DL: u8 = 0x0B;
+pub const RTC_DH: u8 = 0x0C;
+
+const CLOCKS_PER_SECOND: usize = 4_194_304;
+
+const SAVE_FOOTER_LENGTH: usize = 48;
+
+/// Bit 0 of DH holds bit 8 of the day counter
+const DH_DAY_BIT_8: u8 = 0b0000_0001;
+const DH_HALT: u8 = 0b0100_0000;
+const DH_DAY_CARRY: u8 = 0b1000_0000;
+
+/// MBC3 real time clock
+///
+/// The clock counts in emulated time while the game runs. To keep in sync with the host between sessions, the clock also remembers the host
+/// timestamp of its last update so that `catch_up` can advance it by the wall-clock time that passed while the emulator was not running.
+pub struct RealTimeClock {
+    seconds: u8,
+    minutes: u8,
+    hours: u8,
+    days: u16,
+    halted: bool,
+    day_carry: bool,
+    latched: [u8; 5],
+    latch_register: u8,
+    clock_accumulator: usize,
+    timestamp: u64,
+}
+
+impl RealTimeClock {
+    pub fn new() -> Self {
+        Self {
+            seconds: 0,
+            minutes: 0,
+            hours: 0,
+            days: 0,
+            halted: false,
+            day_carry: false,
+            latched: [0; 5],
+            latch_register: 0xFF,
+            clock_accumulator: 0,
+            timestamp: host_timestamp(),
+        }
+    }
+
+    pub fn step(&mut self) {
+        if self.halted {
+            return;
+        }
+
+        self.clock_accumulator += 4;
+        if self.clock_accumulator >= CLOCKS_PER_SECOND {
+            self.clock_accumulator -= CLOCKS_PER_SECOND;
+            self.advance_seconds(1);
+            self.timestamp = host_timestamp();
+        }
+    }
+
+    /// Advances the clock by the host time that passed since the last update.
+    pub fn catch_up(&mut self) {
+        let now = host_timestamp();
+        if !self.halted && now > self.timestamp {
+            self.advance_seconds(now - self.timestamp);
+        }
+        self.timestamp = now;
+    }
+
+    pub fn advance_seconds(&mut self, seconds: u64) {
+        // THE REGISTERS MAY HOLD OUT OF RANGE VALUES WRITTEN BY THE GAME, THOSE COUNT UP TO THEIR BIT LIMIT AND WRAP WITHOUT CARRY
+        let mut remaining = seconds;
+        while remaining > 0 && !self.is_in_range() {
+            self.tick_second();
+            remaining -= 1;
+        }
+        if remaining == 0 {
+            return;
+        }
+
+        // BULK ADVANCE ONCE ALL REGISTERS ARE BACK IN RANGE
+        let total =
+            self.seconds as u64 + self.minutes as u64 * 60 + self.hours as u64 * 3600 + remaining;
+        self.seconds = (total % 60) as u8;
+        self.minutes = (total / 60 % 60) as u8;
+        self.hours = (total / 3600 % 24) as u8;
+        let days = self.days as u64 + total / 86400;
+        if days > 0x1FF {
+            self.day_carry = true;
+        }
+        self.days = (days & 0x1FF) as u16;
+    }
+
+    fn is_in_range(&self) -> bool {
+        self.seconds < 60 && self.minutes < 60 && self.hours < 24
+    }
+
+    fn tick_second(&mut self) {
+        self.seconds = (self.seconds + 1) & 0x3F;
+        if self.seconds != 60 {
+            return;
+        }
+        self.seconds = 0;
+
+        self.minutes = (self.minutes + 1) & 0x3F;
+        if self.minutes != 60 {
+            return;
+        }
+        self.minutes = 0;
+
+        self.hours = (self.hours + 1) & 0x1F;
+        if self.hours != 24 {
+            return;
+        }
+        self.hours = 0;
+
+        self.days += 1;
+        if self.days > 0x1FF {
+            self.days = 0;
+            self.day_carry = true;
+        }
+    }
+
+    /// Writing 0x00 followed by 0x01 copies the current time into the latched registers.
+    pub fn write_latch(&mut self, value: u8) {
+        if self.latch_register == 0x00 && value == 0x01 {
+            self.latched = [
+                self.seconds,
+                self.minutes,
+                self.hours,
+                self.days as u8,
+                self.read_dh(),
+            ];
+        }
+        self.latch_register = value;
+    }
+
+    pub fn read_register(&self, register: u8) -> u8 {
+        match register {
+            RTC_S..=RTC_DH => self.latched[(register - RTC_S) as usize],
+            _ => 0xFF,
+        }
+    }
+
+    pub fn write_register(&mut self, register: u8, value: u8) {
+        match register {
+            RTC_S => {
+                self.seconds = value & 0x3F;
+                self.clock_accumulator = 0;
+            }
+            RTC_M => {
+                self.minutes = value & 0x3F;
+            }
+            RTC_H => {
+                self.hours = value & 0x1F;
+            }
+            RTC_DL => {
+                self.days = (self.days & 0x100) | value as u16;
+            }
+            RTC_DH => {
+                self.days = (self.days & 0xFF) | (((value & DH_DAY_BIT_8) as u16) << 8);
+                self.halted = value & DH_HALT > 0;
+                self.day_carry = value & DH_DAY_CARRY > 0;
+            }
+            _ => {}
+        }
+
+        // WRITES ARE VISIBLE IN THE LATCHED REGISTERS IMMEDIATELY
+        if (RTC_S..=RTC_DH).contains(&register) {
+            self.latched[(register - RTC_S) as usize] = match register {
+                RTC_S => self.seconds,
+                RTC_M => self.minutes,
+                RTC_H => self.hours,
+                RTC_DL => self.days as u8,
+                _ => self.read_dh(),
+            };
+        }
+    }
+
+    /// Serializes the clock into the 48 byte footer that BGB and VBA-M append to MBC3 save files.
+    /// The current and the latched registers are stored as 32 bit little endian values, followed by a 64 bit unix timestamp.
+    pub fn to_save_footer(&self) -> Vec<u8> {
+        let registers = [
+            self.seconds,
+            self.minutes,
+            self.hours,
+            self.days as u8,
+            self.read_dh(),
+        ];
+
+        let mut footer = Vec::with_capacity(SAVE_FOOTER_LENGTH);
+        for register in registers.iter().chain(self.latched.iter()) {
+            footer.extend_from_slice(&(*register as u32).to_le_bytes());
+        }
+        footer.extend_from_slice(&self.timestamp.to_le_bytes());
+        footer
+    }
+
+    /// Restores the clock from a save file footer, older emulators wrote the timestamp with only 32 bits.
+    pub fn load_save_footer(&mut self, footer: &[u8]) {
+        if footer.len() < SAVE_FOOTER_LENGTH - 4 {
+            return;
+        }
+
+        let mut registers = [0u8; 10];
+        for (index, register) in registers.iter_mut().enumerate() {
+            *register = footer[index * 4];
+        }
+
+        self.seconds = registers[0] & 0x3F;
+        self.minutes = registers[1] & 0x3F;
+        self.hours = registers[2] & 0x1F;
+        self.days = ((registers[4] & DH_DAY_BIT_8) as u16) << 8 | registers[3] as u16;
+        self.halted = registers[4] & DH_HALT > 0;
+        self.day_carry = registers[4] & DH_DAY_CARRY > 0;
+        self.latched.copy_from_slice(&registers[5..10]);
+        self.clock_accumulator = 0;
+
+        let mut timestamp = [0u8; 8];
+        let timestamp_length = min(8, footer.len() - 40);
+        timestamp[0..timestamp_length].copy_from_slice(&footer[40..40 + timestamp_length]);
+        self.timestamp = u64::from_le_bytes(timestamp);
+    }
+
+    fn read_dh(&self) -> u8 {
+        let mut dh = ((self.days >> 8) as u8) & DH_DAY_BIT_8;
+        if self.halted {
+            dh |= DH_HALT;
+        }
+        if self.day_carry {
+            dh |= DH_DAY_CARRY;
+        }
+        dh
+    }
+}
+
+pub fn host_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0)
+}
@@ -0,0 +1,82 @@
+use std::io::{Cursor, Read};
+
+use flate2::read::GzDecoder;
+use zip::ZipArchive;
+
+use crate::gameboy::mbc::cartridge_header::MAX_ROM_SIZE;
+use crate::gameboy::mbc::rom_error::RomError;
+
+const ZIP_MAGIC: [u8; 4] = [0x50, 0x4B, 0x03, 0x04];
+const GZIP_MAGIC: [u8; 2] = [0x1F, 0x8B];
+
+const ROM_EXTENSIONS: [&str; 2] = [".gb", ".gbc"];
+
+/// Returns the ROM contained in a zip or gzip archive. Data that is not compressed is returned as it is.
+///
+/// For zip archives the entry with the given name is extracted, or the first `.gb`/`.gbc` entry if no name is given.
+pub fn extract(data: Vec<u8>, entry_name: Option<&str>) -> Result<Vec<u8>, RomError> {
+    if data.starts_with(&ZIP_MAGIC) {
+        return extract_zip(data, entry_name);
+    }
+    if data.starts_with(&GZIP_MAGIC) {
+        return extract_gzip(data.as_slice());
+    }
+    Ok(data)
+}
+
+fn extract_zip(data: Vec<u8>, entry_name: Option<&str>) -> Result<Vec<u8>, RomError> {
+    let mut archive =
+        ZipArchive::new(Cursor::new(data)).map_err(|error| RomError::Archive(error.to_string()))?;
+
+    let entry_name = match entry_name {
+        Some(name) => name.to_string(),
+        None => find_first_rom(&mut archive)?,
+    };
+
+    let mut entry = archive
+        .by_name(&entry_name)
+        .map_err(|error| RomError::Archive(format!("{}: {}", entry_name, error)))?;
+    read_limited(entry).map_err(|error| RomError::Archive(format!("{}: {}", entry_name, error)))
+}
+
+fn find_first_rom(archive: &mut ZipArchive<Cursor<Vec<u8>>>) -> Result<String, RomError> {
+    for index in 0..archive.len() {
+        let entry = archive
+            .by_index_raw(index)
+            .map_err(|error| RomError::Archive(error.to_string()))?;
+        if entry.is_file() && is_rom_file_name(entry.name()) {
+            return Ok(entry.name().to_string());
+        }
+    }
+    Err(RomError::Archive(String::from(
+        "no .gb or .gbc file found in zip archive",
+    )))
+}
+
+fn extract_gzip(data: &[u8]) -> Result<Vec<u8>, RomError> {
+    read_limited(GzDecoder::new(data)).map_err(RomError::Archive)
+}
+
+/// Decompresses at most one byte more than the largest ROM. The sizes stored in archives can't be trusted, so a broken or hostile
+/// archive fails here instead of exhausting memory.
+fn read_limited(reader: impl Read) -> Result<Vec<u8>, String> {
+    let mut rom = Vec::new();
+    reader
+        .take(MAX_ROM_SIZE as u64 + 1)
+        .read_to_end(&mut rom)
+        .map_err(|error| error.to_string())?;
+    if rom.len() > MAX_ROM_SIZE {
+        return Err(format!(
+            "larger than the largest ROM size of {} bytes",
+            MAX_ROM_SIZE
+        ));
+    }
+    Ok(rom)
+}
+
+fn is_rom_file_name(name: &str) -> bool {
+    let name = name.to_ascii_lowercase();
+    ROM_EXTENSIONS
+        .iter()
+        .any(|extension| name.ends_with(extension))
+}
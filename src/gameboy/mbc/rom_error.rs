@@ -0,0 +1,51 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io;
+
+/// Errors that can occur while loading a ROM
+#[derive(Debug)]
+pub enum RomError {
+    /// The ROM file does not exist or could not be read
+    Io { path: String, error: io::Error },
+    /// The ROM is shorter than its header
+    Truncated { length: usize, expected: usize },
+    /// The cartridge type in the header is not emulated
+    UnsupportedMapper(u8),
+    /// The header contains values that make it impossible to set up the cartridge
+    BadHeader(String),
+    /// The ROM could not be extracted from its zip or gzip archive
+    Archive(String),
+    /// The IPS, UPS or BPS patch is invalid or was made for a different ROM
+    Patch(String),
+    /// The boot ROM dump has a size that matches none of the known boot ROMs
+    BootRom(String),
+}
+
+impl Display for RomError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RomError::Io { path, error } => write!(f, "Error reading file {}: {}", path, error),
+            RomError::Truncated { length, expected } => write!(
+                f,
+                "ROM is truncated: expected at least {} bytes but found {}",
+                expected, length
+            ),
+            RomError::UnsupportedMapper(cartridge_type) => {
+                write!(f, "Unsupported MBC found in ROM: {:#04X}", cartridge_type)
+            }
+            RomError::BadHeader(reason) => write!(f, "Bad cartridge header: {}", reason),
+            RomError::Archive(reason) => write!(f, "Error extracting ROM from archive: {}", reason),
+            RomError::Patch(reason) => write!(f, "Error applying patch: {}", reason),
+            RomError::BootRom(reason) => write!(f, "Invalid boot ROM: {}", reason),
+        }
+    }
+}
+
+impl Error for RomError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            RomError::Io { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
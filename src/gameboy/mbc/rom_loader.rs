@@ -1,16 +1,123 @@
+use std::borrow::Cow;
 use std::fs;
 
+use crate::gameboy::mbc::bbd::Bbd;
+use crate::gameboy::mbc::cartridge_header::{CartridgeHeader, CgbSupport};
+use crate::gameboy::mbc::huc1::HuC1;
+use crate::gameboy::mbc::huc3::HuC3;
 use crate::gameboy::mbc::mbc::Mbc;
 use crate::gameboy::mbc::mbc0::Mbc0;
 use crate::gameboy::mbc::mbc1::Mbc1;
+use crate::gameboy::mbc::mbc2::Mbc2;
+use crate::gameboy::mbc::mbc3::Mbc3;
+use crate::gameboy::mbc::mbc5::Mbc5;
+use crate::gameboy::mbc::mbc7::Mbc7;
+use crate::gameboy::mbc::mmm01;
+use crate::gameboy::mbc::mmm01::Mmm01;
+use crate::gameboy::mbc::pocket_camera::PocketCamera;
+use crate::gameboy::mbc::rom_archive;
+use crate::gameboy::mbc::rom_error::RomError;
+use crate::gameboy::mbc::rom_patch;
+use crate::gameboy::mbc::sachen::Sachen;
+use crate::gameboy::mbc::tama5::Tama5;
+use crate::gameboy::mbc::wisdom_tree::WisdomTree;
 
-pub fn load(path: String) -> Box<dyn Mbc> {
-    let path_copy = path.clone();
-    let data = fs::read(path)
-        .unwrap_or_else(|error| panic!("Error reading file {}: {}", path_copy, error));
-    match data[0x147] {
-        0x0 => Box::from(Mbc0::new(data.as_slice())),
-        0x1 => Box::from(Mbc1::new(data.as_slice())),
-        _ => panic!("Unsupported MBC found in ROM: {}", data[0x147]),
+pub fn load(path: String) -> Result<Box<dyn Mbc>, RomError> {
+    load_with_patch(path, None, None)
+}
+
+/// Loads a ROM and applies the given IPS, UPS or BPS patch. Without an explicit patch, a patch with the same name as the ROM is used if
+/// there is one. The entry name selects the ROM in a zip archive, see `read_rom`.
+pub fn load_with_patch(
+    path: String,
+    entry_name: Option<&str>,
+    patch_path: Option<String>,
+) -> Result<Box<dyn Mbc>, RomError> {
+    let patch_path = patch_path.or_else(|| rom_patch::find_patch(&path));
+    let mut data = read_rom(path, entry_name)?;
+    if let Some(patch_path) = patch_path {
+        println!("Applying patch {}", patch_path);
+        data = rom_patch::apply_file(data, &patch_path)?;
+    }
+    load_from_bytes(data.as_slice())
+}
+
+/// Reads a ROM file, zip and gzip archives are extracted. The entry name selects the file to extract from a zip archive.
+pub fn read_rom(path: String, entry_name: Option<&str>) -> Result<Vec<u8>, RomError> {
+    let data = fs::read(&path).map_err(|error| RomError::Io { path, error })?;
+    rom_archive::extract(data, entry_name)
+}
+
+pub fn load_from_bytes(data: &[u8]) -> Result<Box<dyn Mbc>, RomError> {
+    // MMM01 CARTRIDGES START WITH THE MENU MAPPED, WHICH IS STORED IN THE LAST 32 KiB AND HOLDS THE HEADER OF THE WHOLE CARTRIDGE
+    if let Some(menu) = Mmm01::find_menu(data) {
+        let header = parse_header(menu, data.len())?;
+        return Ok(Box::from(Mmm01::new(data, header)));
+    }
+
+    // UNLICENSED CARTRIDGES DON'T NAME THEIR MAPPER IN THE HEADER
+    if Sachen::detect(data) {
+        let header = parse_header(&Sachen::unscramble_header(data), data.len())?;
+        let data = pad_rom(data, &header);
+        let mmc2 = header.cgb_support != CgbSupport::None;
+        return Ok(Box::from(Sachen::new(&data, header, mmc2)));
+    }
+
+    let header = parse_header(data, data.len())?;
+    let data = &*pad_rom(data, &header);
+    if WisdomTree::detect(data, &header) {
+        return Ok(Box::from(WisdomTree::new(data, header)));
+    }
+    if Bbd::detect(data, &header) {
+        return Ok(Box::from(Bbd::new(data, header)));
+    }
+
+    Ok(match header.cartridge_type {
+        0x00 | 0x08 | 0x09 => Box::from(Mbc0::new(data, header)),
+        0x01..=0x03 => Box::from(Mbc1::new(data, header)),
+        0x05 | 0x06 => Box::from(Mbc2::new(data, header)),
+        0x0B..=0x0D => {
+            // SOME DUMPS STORE THE MENU FIRST, MOVE IT TO THE END WHERE THE CARTRIDGE EXPECTS IT
+            let mut rotated = data.to_vec();
+            rotated.rotate_left(mmm01::MENU_SIZE.min(data.len()));
+            Box::from(Mmm01::new(&rotated, header))
+        }
+        0x0F | 0x10 => Box::from(Mbc3::new(data, header, true)),
+        0x11..=0x13 => Box::from(Mbc3::new(data, header, false)),
+        0x19..=0x1B => Box::from(Mbc5::new(data, header, false)),
+        0x1C..=0x1E => Box::from(Mbc5::new(data, header, true)),
+        0x22 => Box::from(Mbc7::new(data, header)),
+        0xFC => Box::from(PocketCamera::new(data, header)),
+        0xFD => Box::from(Tama5::new(data, header)),
+        0xFE => Box::from(HuC3::new(data, header)),
+        0xFF => Box::from(HuC1::new(data, header)),
+        cartridge_type => return Err(RomError::UnsupportedMapper(cartridge_type)),
+    })
+}
+
+/// Parses and reports the header, including a ROM that is smaller than the header claims.
+fn parse_header(header_data: &[u8], rom_length: usize) -> Result<CartridgeHeader, RomError> {
+    let header = CartridgeHeader::parse(header_data)?;
+
+    println!("Loading {}", header);
+    for problem in header.validate() {
+        println!("Warning: {}, the ROM may be corrupted", problem);
+    }
+    if rom_length < header.rom_size {
+        println!(
+            "Warning: ROM is truncated, expected {} bytes but found {}, the rest reads as 0xFF",
+            header.rom_size, rom_length
+        );
+    }
+    Ok(header)
+}
+
+/// Fills a truncated ROM up to the size from the header with 0xFF, like the open bus of a missing ROM chip.
+fn pad_rom<'a>(data: &'a [u8], header: &CartridgeHeader) -> Cow<'a, [u8]> {
+    if data.len() >= header.rom_size {
+        return Cow::Borrowed(data);
     }
+    let mut padded = data.to_vec();
+    padded.resize(header.rom_size, 0xFF);
+    Cow::Owned(padded)
 }
@@ -0,0 +1,310 @@
+use std::fs;
+use std::path::Path;
+
+use crate::gameboy::mbc::cartridge_header::MAX_ROM_SIZE;
+use crate::gameboy::mbc::rom_error::RomError;
+
+const IPS_MAGIC: &[u8] = b"PATCH";
+const IPS_EOF: u32 = 0x454F46;
+const UPS_MAGIC: &[u8] = b"UPS1";
+const BPS_MAGIC: &[u8] = b"BPS1";
+
+/// UPS and BPS patches end with the CRC32 of the source, the target and the patch itself
+const CHECKSUM_FOOTER_LENGTH: usize = 12;
+
+pub const PATCH_EXTENSIONS: [&str; 3] = ["ips", "ups", "bps"];
+
+/// Looks for a patch with the same name as the ROM, e.g. `Game.ips` next to `Game.gb`.
+pub fn find_patch(rom_path: &str) -> Option<String> {
+    PATCH_EXTENSIONS
+        .iter()
+        .map(|extension| Path::new(rom_path).with_extension(extension))
+        .find(|patch_path| patch_path.is_file())
+        .map(|patch_path| patch_path.to_string_lossy().into())
+}
+
+/// Applies the IPS, UPS or BPS patch file at the given path to the ROM. The format is detected from the file's header.
+pub fn apply_file(rom: Vec<u8>, patch_path: &str) -> Result<Vec<u8>, RomError> {
+    let patch = fs::read(patch_path).map_err(|error| RomError::Io {
+        path: patch_path.to_string(),
+        error,
+    })?;
+    apply(rom, &patch)
+}
+
+pub fn apply(rom: Vec<u8>, patch: &[u8]) -> Result<Vec<u8>, RomError> {
+    if patch.starts_with(IPS_MAGIC) {
+        return apply_ips(rom, patch);
+    }
+    if patch.starts_with(UPS_MAGIC) {
+        return apply_ups(&rom, patch);
+    }
+    if patch.starts_with(BPS_MAGIC) {
+        return apply_bps(&rom, patch);
+    }
+    Err(patch_error("unknown patch format"))
+}
+
+fn apply_ips(mut rom: Vec<u8>, patch: &[u8]) -> Result<Vec<u8>, RomError> {
+    let mut reader = PatchReader::new(patch, IPS_MAGIC.len());
+    loop {
+        let offset = reader.read_u24_be()?;
+        if offset == IPS_EOF {
+            break;
+        }
+        let offset = offset as usize;
+
+        let length = reader.read_u16_be()? as usize;
+        if length == 0 {
+            // RUN LENGTH ENCODED RECORD
+            let run_length = reader.read_u16_be()? as usize;
+            let value = reader.read_byte()?;
+            if rom.len() < offset + run_length {
+                rom.resize(offset + run_length, 0);
+            }
+            rom[offset..offset + run_length].fill(value);
+        } else {
+            let data = reader.read_bytes(length)?;
+            if rom.len() < offset + length {
+                rom.resize(offset + length, 0);
+            }
+            rom[offset..offset + length].copy_from_slice(data);
+        }
+    }
+
+    // THE TRUNCATION EXTENSION STORES THE NEW SIZE AFTER THE EOF MARKER
+    if reader.remaining() >= 3 {
+        let size = reader.read_u24_be()? as usize;
+        rom.truncate(size);
+    }
+
+    Ok(rom)
+}
+
+fn apply_ups(rom: &[u8], patch: &[u8]) -> Result<Vec<u8>, RomError> {
+    let checksums = verify_patch_checksum(patch)?;
+    let mut reader = PatchReader::new(
+        &patch[..patch.len() - CHECKSUM_FOOTER_LENGTH],
+        UPS_MAGIC.len(),
+    );
+    let source_size = reader.read_varint()?;
+    let target_size = verify_target_size(reader.read_varint()?)?;
+    verify_checksum("source ROM", rom, checksums.source)?;
+    if rom.len() != source_size {
+        return Err(patch_error("patch was made for a ROM of different size"));
+    }
+
+    // EVERY HUNK SKIPS UNCHANGED BYTES AND THEN XORS THE TARGET UNTIL IT HITS A ZERO
+    let mut target = rom.to_vec();
+    target.resize(target_size, 0);
+    let mut position = 0;
+    while reader.remaining() > 0 {
+        position += reader.read_varint()?;
+        loop {
+            let value = reader.read_byte()?;
+            if value == 0 {
+                position += 1;
+                break;
+            }
+            if position < target_size {
+                target[position] ^= value;
+            }
+            position += 1;
+        }
+    }
+
+    verify_checksum("patched ROM", &target, checksums.target)?;
+    Ok(target)
+}
+
+fn apply_bps(rom: &[u8], patch: &[u8]) -> Result<Vec<u8>, RomError> {
+    let checksums = verify_patch_checksum(patch)?;
+    let mut reader = PatchReader::new(
+        &patch[..patch.len() - CHECKSUM_FOOTER_LENGTH],
+        BPS_MAGIC.len(),
+    );
+    let source_size = reader.read_varint()?;
+    let target_size = verify_target_size(reader.read_varint()?)?;
+    let metadata_size = reader.read_varint()?;
+    reader.read_bytes(metadata_size)?;
+    verify_checksum("source ROM", rom, checksums.source)?;
+    if rom.len() != source_size {
+        return Err(patch_error("patch was made for a ROM of different size"));
+    }
+
+    let mut target = Vec::with_capacity(target_size);
+    let mut source_offset: usize = 0;
+    let mut target_offset: usize = 0;
+    while reader.remaining() > 0 {
+        let data = reader.read_varint()?;
+        let length = (data >> 2) + 1;
+        if target.len() + length > target_size {
+            return Err(patch_error("patch writes beyond the end of the ROM"));
+        }
+
+        match data & 0b11 {
+            // SOURCE READ
+            0 => {
+                let start = target.len();
+                let bytes = rom
+                    .get(start..start + length)
+                    .ok_or_else(|| patch_error("patch reads beyond the end of the ROM"))?;
+                target.extend_from_slice(bytes);
+            }
+            // TARGET READ
+            1 => {
+                target.extend_from_slice(reader.read_bytes(length)?);
+            }
+            // SOURCE COPY
+            2 => {
+                source_offset = reader.read_relative_offset(source_offset)?;
+                let bytes = rom
+                    .get(source_offset..source_offset + length)
+                    .ok_or_else(|| patch_error("patch reads beyond the end of the ROM"))?;
+                target.extend_from_slice(bytes);
+                source_offset += length;
+            }
+            // TARGET COPY, SOURCE AND DESTINATION MAY OVERLAP SO THIS HAS TO BE DONE BYTE BY BYTE
+            _ => {
+                target_offset = reader.read_relative_offset(target_offset)?;
+                for _ in 0..length {
+                    let value = *target
+                        .get(target_offset)
+                        .ok_or_else(|| patch_error("patch reads beyond the end of the ROM"))?;
+                    target.push(value);
+                    target_offset += 1;
+                }
+            }
+        }
+    }
+
+    if target.len() != target_size {
+        return Err(patch_error("patched ROM has the wrong size"));
+    }
+    verify_checksum("patched ROM", &target, checksums.target)?;
+    Ok(target)
+}
+
+struct Checksums {
+    source: u32,
+    target: u32,
+}
+
+fn verify_patch_checksum(patch: &[u8]) -> Result<Checksums, RomError> {
+    if patch.len() < 4 + CHECKSUM_FOOTER_LENGTH {
+        return Err(patch_error("patch is truncated"));
+    }
+
+    let footer = &patch[patch.len() - CHECKSUM_FOOTER_LENGTH..];
+    let read_u32 = |offset: usize| {
+        u32::from_le_bytes([
+            footer[offset],
+            footer[offset + 1],
+            footer[offset + 2],
+            footer[offset + 3],
+        ])
+    };
+    verify_checksum("patch", &patch[..patch.len() - 4], read_u32(8))?;
+
+    Ok(Checksums {
+        source: read_u32(0),
+        target: read_u32(4),
+    })
+}
+
+fn verify_checksum(name: &str, data: &[u8], expected: u32) -> Result<(), RomError> {
+    let actual = crc32fast::hash(data);
+    if actual != expected {
+        return Err(patch_error(&format!(
+            "CRC32 of the {} is {:08X} but the patch expects {:08X}",
+            name, actual, expected
+        )));
+    }
+    Ok(())
+}
+
+/// The target size comes from the patch, so it is checked before any memory is allocated for it.
+fn verify_target_size(target_size: usize) -> Result<usize, RomError> {
+    if target_size > MAX_ROM_SIZE {
+        return Err(patch_error(&format!(
+            "patched ROM would have {} bytes, more than the largest ROM size of {} bytes",
+            target_size, MAX_ROM_SIZE
+        )));
+    }
+    Ok(target_size)
+}
+
+fn patch_error(reason: &str) -> RomError {
+    RomError::Patch(reason.to_string())
+}
+
+struct PatchReader<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> PatchReader<'a> {
+    fn new(data: &'a [u8], position: usize) -> Self {
+        Self { data, position }
+    }
+
+    fn remaining(&self) -> usize {
+        self.data.len().saturating_sub(self.position)
+    }
+
+    fn read_byte(&mut self) -> Result<u8, RomError> {
+        Ok(self.read_bytes(1)?[0])
+    }
+
+    fn read_bytes(&mut self, length: usize) -> Result<&'a [u8], RomError> {
+        let bytes = self
+            .data
+            .get(self.position..self.position + length)
+            .ok_or_else(|| patch_error("patch is truncated"))?;
+        self.position += length;
+        Ok(bytes)
+    }
+
+    fn read_u16_be(&mut self) -> Result<u16, RomError> {
+        let bytes = self.read_bytes(2)?;
+        Ok(u16::from_be_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn read_u24_be(&mut self) -> Result<u32, RomError> {
+        let bytes = self.read_bytes(3)?;
+        Ok(u32::from_be_bytes([0, bytes[0], bytes[1], bytes[2]]))
+    }
+
+    /// UPS and BPS encode numbers with 7 bits per byte, the highest bit marks the last byte
+    fn read_varint(&mut self) -> Result<usize, RomError> {
+        let mut value: usize = 0;
+        let mut shift: usize = 1;
+        loop {
+            let byte = self.read_byte()?;
+            value = (byte as usize & 0x7F)
+                .checked_mul(shift)
+                .and_then(|digit| value.checked_add(digit))
+                .ok_or_else(|| patch_error("number in patch is too large"))?;
+            if byte & 0x80 > 0 {
+                return Ok(value);
+            }
+            shift = shift
+                .checked_mul(0x80)
+                .and_then(|shift| value.checked_add(shift).map(|_| shift))
+                .ok_or_else(|| patch_error("number in patch is too large"))?;
+            value += shift;
+        }
+    }
+
+    /// BPS copy commands move their read offset by a signed distance, the lowest bit is the sign
+    fn read_relative_offset(&mut self, offset: usize) -> Result<usize, RomError> {
+        let data = self.read_varint()?;
+        let distance = data >> 1;
+        let result = if data & 1 > 0 {
+            offset.checked_sub(distance)
+        } else {
+            offset.checked_add(distance)
+        };
+        result.ok_or_else(|| patch_error("patch reads beyond the start of the ROM"))
+    }
+}
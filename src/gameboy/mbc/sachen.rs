@@ -0,0 +1,188 @@
+use std::fmt::{Display, Formatter};
+
+use crate::gameboy::mbc::cartridge_header;
+use crate::gameboy::mbc::cartridge_header::CartridgeHeader;
+use crate::gameboy::mbc::mbc;
+use crate::gameboy::mbc::mbc::Mbc;
+use crate::gameboy::memory::memory::Memory;
+
+const ROM_BANK_SIZE: usize = 0x4000;
+
+/// The boot ROM's logo check unlocks the cartridge with the 49th read from the header area
+const UNLOCK_READ_COUNT: u8 = 0x31;
+
+/// Unlicensed Sachen MMC1 and MMC2 mappers
+///
+/// Sachen cartridges show their own logo, but present the Nintendo logo from 0x0184 to the boot ROM until its logo check is done. The
+/// address lines of the header area are scrambled on top of that. The MMC2 adds a second lock stage for the CGB boot ROM, it is triggered by
+/// reads outside of the cartridge which the mapper doesn't see here, so both mappers behave the same.
+///
+/// Banking works with a base bank and a mask: the bits set in the mask come from the base bank, the others from the selected bank.
+pub struct Sachen {
+    rom_banks: Vec<[u8; ROM_BANK_SIZE]>,
+    base_bank: u8,
+    bank_mask: u8,
+    unmasked_bank: u8,
+    mmc2: bool,
+    locked: bool,
+    header_reads: u8,
+    header: CartridgeHeader,
+}
+
+impl Sachen {
+    pub fn new(cartridge_data: &[u8], header: CartridgeHeader, mmc2: bool) -> Self {
+        let bank_count = (cartridge_data.len() / ROM_BANK_SIZE).clamp(2, 256);
+        let mut result = Self {
+            rom_banks: vec![[0; ROM_BANK_SIZE]; bank_count],
+            base_bank: 0,
+            bank_mask: 0,
+            unmasked_bank: 1,
+            mmc2,
+            locked: true,
+            header_reads: 0,
+            header,
+        };
+
+        // COPY ROM BANKS
+        for (bank_index, bank_data) in cartridge_data
+            .chunks(ROM_BANK_SIZE)
+            .take(bank_count)
+            .enumerate()
+        {
+            result.rom_banks[bank_index][0..bank_data.len()].copy_from_slice(bank_data);
+        }
+
+        result
+    }
+
+    /// Sachen cartridges have a foreign logo in the header, but the Nintendo logo at the scrambled addresses the boot ROM sees while the
+    /// cartridge is locked.
+    pub fn detect(data: &[u8]) -> bool {
+        let logo_range = cartridge_header::LOGO..cartridge_header::LOGO + mbc::NINTENDO_LOGO.len();
+        if data.len() < 0x8000 || data[logo_range.clone()] == mbc::NINTENDO_LOGO {
+            return false;
+        }
+
+        logo_range
+            .zip(mbc::NINTENDO_LOGO.iter())
+            .all(|(address, &logo)| data[unscramble(address as u16 | 0x80) as usize] == logo)
+    }
+
+    /// Returns a copy of the ROM with the header area in the order the CPU sees it.
+    pub fn unscramble_header(data: &[u8]) -> Vec<u8> {
+        let mut result = data.to_vec();
+        for address in 0x0100..0x0200 {
+            result[address] = data[unscramble(address as u16) as usize];
+        }
+        result
+    }
+
+    fn low_rom_bank(&self) -> usize {
+        (self.base_bank & self.bank_mask) as usize % self.rom_banks.len()
+    }
+
+    fn high_rom_bank(&self) -> usize {
+        let bank = (self.unmasked_bank & !self.bank_mask) | (self.base_bank & self.bank_mask);
+        bank as usize % self.rom_banks.len()
+    }
+
+    /// The base bank and the mask can only be changed while the selected bank has bits 4 and 5 set
+    fn is_outer_bank_writable(&self) -> bool {
+        self.unmasked_bank & 0b11_0000 == 0b11_0000
+    }
+}
+
+/// Swaps address lines A0 with A6 and A1 with A4
+fn unscramble(address: u16) -> u16 {
+    (address & 0xFFAC)
+        | (address & 0x40) >> 6
+        | (address & 0x10) >> 3
+        | (address & 0x02) << 3
+        | (address & 0x01) << 6
+}
+
+impl Memory for Sachen {
+    fn accepts_address(&self, address: u16) -> bool {
+        address < 0x8000 || (0xA000..0xC000).contains(&address)
+    }
+
+    fn read_byte(&self, address: u16) -> u8 {
+        let mut address = address;
+        if address & 0xFF00 == 0x0100 {
+            // WHILE LOCKED, THE HEADER READS ARE REDIRECTED TO THE NINTENDO LOGO
+            if self.locked {
+                address |= 0x80;
+            }
+            address = unscramble(address);
+        }
+
+        if address < 0x4000 {
+            return self.rom_banks[self.low_rom_bank()][address as usize];
+        }
+
+        if (0x4000..0x8000).contains(&address) {
+            return self.rom_banks[self.high_rom_bank()][(address - 0x4000) as usize];
+        }
+
+        // THERE IS NO EXTERNAL RAM
+        0xFF
+    }
+
+    fn write_byte(&mut self, address: u16, value: u8) {
+        if address < 0x2000 {
+            // SELECT BASE BANK
+            if self.is_outer_bank_writable() {
+                self.base_bank = value;
+            }
+        } else if (0x2000..0x4000).contains(&address) {
+            // SELECT ROM BANK NUMBER
+            self.unmasked_bank = if value == 0 { 1 } else { value };
+        } else if (0x4000..0x6000).contains(&address) {
+            // SELECT BANK MASK
+            if self.is_outer_bank_writable() {
+                self.bank_mask = value;
+            }
+        }
+    }
+}
+
+impl Display for Sachen {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.mmc2 {
+            return write!(f, "Sachen MMC2 running game {}", self.get_game_name());
+        }
+        write!(f, "Sachen MMC1 running game {}", self.get_game_name())
+    }
+}
+
+impl Mbc for Sachen {
+    fn get_header(&self) -> &CartridgeHeader {
+        &self.header
+    }
+
+    fn get_rom_bank(&self, address: u16) -> usize {
+        if address < 0x4000 {
+            self.low_rom_bank()
+        } else {
+            self.high_rom_bank()
+        }
+    }
+
+    /// Counts the header reads of the boot ROM's logo check, the one that unlocks the cartridge already sees the real header.
+    fn on_cpu_read(&mut self, address: u16) {
+        if self.locked && address & 0xFF00 == 0x0100 {
+            self.header_reads += 1;
+            self.locked = self.header_reads < UNLOCK_READ_COUNT;
+        }
+    }
+
+    fn skip_boot(&mut self) {
+        self.locked = false;
+    }
+
+    fn export_battery_ram(&self) -> Vec<u8> {
+        Vec::new()
+    }
+
+    fn import_battery_ram(&mut self, _data: &[u8]) {}
+}
@@ -0,0 +1,202 @@
+/// The 93LC56 has 2 KBit organized as 128 words of 16 bit
+const WORD_COUNT: usize = 128;
+
+/// Opcode and address are sent as 10 bits after the start bit
+const COMMAND_LENGTH: u8 = 10;
+
+const WORD_LENGTH: u8 = 16;
+
+/// 93LC56 serial EEPROM as used by MBC7 cartridges. The game bit-bangs the Microwire interface through the chip select, clock and data
+/// in lines, every command is shifted in on the rising clock edge.
+pub struct SerialEeprom {
+    words: [u16; WORD_COUNT],
+    state: State,
+    write_enabled: bool,
+    chip_select: bool,
+    clock: bool,
+    data_in: bool,
+    data_out: bool,
+}
+
+enum State {
+    /// Waiting for the start bit
+    Idle,
+    /// Receiving opcode and address
+    Command { bits: u16, length: u8 },
+    /// Shifting out a word, the leading dummy zero bit has already been sent
+    Read { word: u16, remaining: u8 },
+    /// Receiving the word for WRITE, or for WRAL if no address is given
+    Write {
+        address: Option<usize>,
+        word: u16,
+        length: u8,
+    },
+}
+
+impl SerialEeprom {
+    pub fn new() -> Self {
+        Self {
+            words: [0xFFFF; WORD_COUNT],
+            state: State::Idle,
+            write_enabled: false,
+            chip_select: false,
+            clock: false,
+            data_in: false,
+            data_out: true,
+        }
+    }
+
+    /// Bit 7 = chip select, bit 6 = clock, bit 1 = data in, bit 0 = data out
+    pub fn read_pins(&self) -> u8 {
+        ((self.chip_select as u8) << 7)
+            | ((self.clock as u8) << 6)
+            | ((self.data_in as u8) << 1)
+            | self.data_out as u8
+    }
+
+    pub fn write_pins(&mut self, value: u8) {
+        let chip_select = value & 0b1000_0000 > 0;
+        let clock = value & 0b0100_0000 > 0;
+        self.data_in = value & 0b10 > 0;
+
+        // DESELECTING THE CHIP ABORTS THE CURRENT COMMAND
+        if !chip_select {
+            self.state = State::Idle;
+        } else if clock && !self.clock {
+            self.clock_in();
+        }
+
+        self.chip_select = chip_select;
+        self.clock = clock;
+    }
+
+    fn clock_in(&mut self) {
+        let bit = self.data_in as u16;
+        self.state = match std::mem::replace(&mut self.state, State::Idle) {
+            State::Idle => {
+                if bit == 1 {
+                    State::Command { bits: 0, length: 0 }
+                } else {
+                    State::Idle
+                }
+            }
+            State::Command { bits, length } => {
+                let bits = (bits << 1) | bit;
+                if length + 1 == COMMAND_LENGTH {
+                    self.execute(bits)
+                } else {
+                    State::Command {
+                        bits,
+                        length: length + 1,
+                    }
+                }
+            }
+            State::Read { word, remaining } => {
+                self.data_out = word & 0x8000 > 0;
+                if remaining > 1 {
+                    State::Read {
+                        word: word << 1,
+                        remaining: remaining - 1,
+                    }
+                } else {
+                    State::Idle
+                }
+            }
+            State::Write {
+                address,
+                word,
+                length,
+            } => {
+                let word = (word << 1) | bit;
+                if length + 1 < WORD_LENGTH {
+                    State::Write {
+                        address,
+                        word,
+                        length: length + 1,
+                    }
+                } else {
+                    if self.write_enabled {
+                        match address {
+                            Some(address) => self.words[address] = word,
+                            None => self.words.fill(word),
+                        }
+                    }
+                    // WRITES COMPLETE IMMEDIATELY, DATA OUT SIGNALS READY
+                    self.data_out = true;
+                    State::Idle
+                }
+            }
+        };
+    }
+
+    fn execute(&mut self, command: u16) -> State {
+        let opcode = (command >> 8) & 0b11;
+        // THE HIGHEST ADDRESS BIT IS IGNORED IN 16 BIT ORGANIZATION
+        let address = (command & 0x7F) as usize;
+        match opcode {
+            // READ
+            0b10 => {
+                self.data_out = false;
+                State::Read {
+                    word: self.words[address],
+                    remaining: WORD_LENGTH,
+                }
+            }
+            // WRITE
+            0b01 => State::Write {
+                address: Some(address),
+                word: 0,
+                length: 0,
+            },
+            // ERASE
+            0b11 => {
+                if self.write_enabled {
+                    self.words[address] = 0xFFFF;
+                }
+                self.data_out = true;
+                State::Idle
+            }
+            // EXTENDED COMMANDS USE THE TOP TWO ADDRESS BITS
+            _ => match (command >> 6) & 0b11 {
+                // EWDS
+                0b00 => {
+                    self.write_enabled = false;
+                    State::Idle
+                }
+                // WRAL
+                0b01 => State::Write {
+                    address: None,
+                    word: 0,
+                    length: 0,
+                },
+                // ERAL
+                0b10 => {
+                    if self.write_enabled {
+                        self.words.fill(0xFFFF);
+                    }
+                    self.data_out = true;
+                    State::Idle
+                }
+                // EWEN
+                _ => {
+                    self.write_enabled = true;
+                    State::Idle
+                }
+            },
+        }
+    }
+
+    /// Returns the EEPROM as 256 bytes with every word stored little endian.
+    pub fn export(&self) -> Vec<u8> {
+        self.words
+            .iter()
+            .flat_map(|word| word.to_le_bytes())
+            .collect()
+    }
+
+    pub fn import(&mut self, data: &[u8]) {
+        for (word, bytes) in self.words.iter_mut().zip(data.chunks_exact(2)) {
+            *word = u16::from_le_bytes([bytes[0], bytes[1]]);
+        }
+    }
+}
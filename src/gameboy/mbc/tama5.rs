@@ -0,0 +1,210 @@
+use std::fmt::{Display, Formatter};
+
+use crate::gameboy::mbc::cartridge_header::CartridgeHeader;
+use crate::gameboy::mbc::mbc::Mbc;
+use crate::gameboy::mbc::tama5_clock::Tama5Clock;
+use crate::gameboy::memory::memory::Memory;
+
+const ROM_BANK_SIZE: usize = 0x4000;
+
+/// The TAMA6 microcontroller keeps 32 bytes of battery backed memory
+const RAM_SIZE: usize = 0x20;
+
+const REGISTER_BANK_LOW: u8 = 0x0;
+const REGISTER_BANK_HIGH: u8 = 0x1;
+const REGISTER_WRITE_LOW: u8 = 0x4;
+const REGISTER_WRITE_HIGH: u8 = 0x5;
+const REGISTER_ADDRESS_HIGH: u8 = 0x6;
+const REGISTER_ADDRESS_LOW: u8 = 0x7;
+const REGISTER_ACTIVE: u8 = 0xA;
+const REGISTER_READ_LOW: u8 = 0xC;
+const REGISTER_READ_HIGH: u8 = 0xD;
+
+/// Bits 1-3 of the high address register select what the access goes to
+const ACCESS_RAM_WRITE: u8 = 0x0;
+const ACCESS_RAM_READ: u8 = 0x1;
+const ACCESS_CLOCK: u8 = 0x2;
+
+const CLOCK_DISABLE: u8 = 0x0;
+const CLOCK_ENABLE: u8 = 0x1;
+const CLOCK_MINUTE_WRITE: u8 = 0x4;
+const CLOCK_HOUR_WRITE: u8 = 0x5;
+const CLOCK_MINUTE_READ: u8 = 0x6;
+const CLOCK_HOUR_READ: u8 = 0x7;
+
+/// Bandai TAMA5 as used by Tamagotchi 3
+///
+/// There are no registers at 0x0000-0x7FFF. Instead the game selects one of 16 nibble registers by writing its index to 0xA001 and then
+/// accesses it through 0xA000. ROM banking, the 32 bytes of memory and the clock of the TAMA6 microcontroller are all driven that way.
+/// The alarm and the clock pages of the TC8521 are not emulated.
+pub struct Tama5 {
+    rom_banks: Vec<[u8; ROM_BANK_SIZE]>,
+    ram: [u8; RAM_SIZE],
+    registers: [u8; 16],
+    selected_register: u8,
+    clock: Tama5Clock,
+    header: CartridgeHeader,
+}
+
+impl Tama5 {
+    pub fn new(cartridge_data: &[u8], header: CartridgeHeader) -> Self {
+        let bank_count = header.rom_bank_count().clamp(2, 32);
+        let mut result = Self {
+            rom_banks: vec![[0; ROM_BANK_SIZE]; bank_count],
+            ram: [0; RAM_SIZE],
+            registers: [0; 16],
+            selected_register: 0,
+            clock: Tama5Clock::new(),
+            header,
+        };
+
+        // COPY ROM BANKS
+        for (bank_index, bank_data) in cartridge_data
+            .chunks(ROM_BANK_SIZE)
+            .take(bank_count)
+            .enumerate()
+        {
+            result.rom_banks[bank_index][0..bank_data.len()].copy_from_slice(bank_data);
+        }
+
+        result
+    }
+
+    fn rom_bank(&self) -> usize {
+        let bank = self.registers[REGISTER_BANK_LOW as usize]
+            | (self.registers[REGISTER_BANK_HIGH as usize] & 0b1) << 4;
+        bank as usize % self.rom_banks.len()
+    }
+
+    fn access(&self) -> u8 {
+        self.registers[REGISTER_ADDRESS_HIGH as usize] >> 1
+    }
+
+    fn address(&self) -> u8 {
+        (self.registers[REGISTER_ADDRESS_HIGH as usize] & 0b1) << 4
+            | self.registers[REGISTER_ADDRESS_LOW as usize]
+    }
+
+    fn output(&self) -> u8 {
+        self.registers[REGISTER_WRITE_HIGH as usize] << 4
+            | self.registers[REGISTER_WRITE_LOW as usize]
+    }
+
+    /// Writing the low address register starts the access selected by the high address register
+    fn execute(&mut self) {
+        let address = self.address();
+        let output = self.output();
+        match self.access() {
+            ACCESS_RAM_WRITE => self.ram[address as usize] = output,
+            ACCESS_CLOCK => match address {
+                CLOCK_DISABLE => self.clock.set_enabled(false),
+                CLOCK_ENABLE => self.clock.set_enabled(true),
+                CLOCK_MINUTE_WRITE => self.clock.set_minutes_bcd(output),
+                CLOCK_HOUR_WRITE => self.clock.set_hours_bcd(output),
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+
+    fn read_result(&self) -> u8 {
+        let address = self.address();
+        match self.access() {
+            ACCESS_RAM_READ => self.ram[address as usize],
+            ACCESS_CLOCK if address == CLOCK_MINUTE_READ => self.clock.minutes_bcd(),
+            ACCESS_CLOCK if address == CLOCK_HOUR_READ => self.clock.hours_bcd(),
+            _ => 0x00,
+        }
+    }
+}
+
+impl Memory for Tama5 {
+    fn accepts_address(&self, address: u16) -> bool {
+        address < 0x8000 || (0xA000..0xC000).contains(&address)
+    }
+
+    fn read_byte(&self, address: u16) -> u8 {
+        if address < 0x4000 {
+            return self.rom_banks[0][address as usize];
+        }
+
+        if (0x4000..0x8000).contains(&address) {
+            return self.rom_banks[self.rom_bank()][(address - 0x4000) as usize];
+        }
+
+        if (0xA000..0xC000).contains(&address) {
+            if address & 0b1 > 0 {
+                return 0xFF;
+            }
+            // ONLY THE LOWER NIBBLE IS CONNECTED
+            return 0xF0
+                | match self.selected_register {
+                    REGISTER_ACTIVE => 0x1,
+                    REGISTER_READ_LOW => self.read_result() & 0xF,
+                    REGISTER_READ_HIGH => self.read_result() >> 4,
+                    _ => 0x0,
+                };
+        }
+
+        0xFF
+    }
+
+    fn write_byte(&mut self, address: u16, value: u8) {
+        if !(0xA000..0xC000).contains(&address) {
+            return;
+        }
+
+        if address & 0b1 > 0 {
+            // SELECT REGISTER
+            self.selected_register = value;
+        } else if (self.selected_register as usize) < self.registers.len() {
+            self.registers[self.selected_register as usize] = value & 0xF;
+            if self.selected_register == REGISTER_ADDRESS_LOW {
+                self.execute();
+            }
+        }
+    }
+}
+
+impl Display for Tama5 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Tama5 running game {}", self.get_game_name())
+    }
+}
+
+impl Mbc for Tama5 {
+    fn get_header(&self) -> &CartridgeHeader {
+        &self.header
+    }
+
+    fn get_rom_bank(&self, address: u16) -> usize {
+        if address < 0x4000 {
+            0
+        } else {
+            self.rom_bank()
+        }
+    }
+
+    fn step(&mut self) {
+        self.clock.step();
+    }
+
+    fn export_battery_ram(&self) -> Vec<u8> {
+        let mut data = self.ram.to_vec();
+
+        // THE CLOCK STATE IS APPENDED AS A FOOTER
+        data.extend_from_slice(&self.clock.to_save_footer());
+        data
+    }
+
+    fn import_battery_ram(&mut self, data: &[u8]) {
+        for (target, source) in self.ram.iter_mut().zip(data) {
+            *target = *source;
+        }
+
+        if data.len() > RAM_SIZE {
+            self.clock.load_save_footer(&data[RAM_SIZE..]);
+        }
+        self.clock.catch_up();
+    }
+}
@@ -0,0 +1,115 @@
+use crate::gameboy::mbc::real_time_clock;
+
+const CLOCKS_PER_SECOND: usize = 4_194_304;
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+const SAVE_FOOTER_LENGTH: usize = 16;
+
+/// TAMA5 real time clock
+///
+/// The TAMA6 controller only exposes minutes and hours to the game, both as BCD values. The clock can be stopped by the game and
+/// remembers the host timestamp of its last update, so `catch_up` can advance it by the time that passed while the emulator was not running.
+pub struct Tama5Clock {
+    second_of_day: u64,
+    enabled: bool,
+    clock_accumulator: usize,
+    timestamp: u64,
+}
+
+impl Tama5Clock {
+    pub fn new() -> Self {
+        Self {
+            second_of_day: 0,
+            enabled: true,
+            clock_accumulator: 0,
+            timestamp: real_time_clock::host_timestamp(),
+        }
+    }
+
+    pub fn step(&mut self) {
+        if !self.enabled {
+            return;
+        }
+
+        self.clock_accumulator += 4;
+        if self.clock_accumulator >= CLOCKS_PER_SECOND {
+            self.clock_accumulator -= CLOCKS_PER_SECOND;
+            self.second_of_day = (self.second_of_day + 1) % SECONDS_PER_DAY;
+            self.timestamp = real_time_clock::host_timestamp();
+        }
+    }
+
+    /// Advances the clock by the host time that passed since the last update.
+    pub fn catch_up(&mut self) {
+        let now = real_time_clock::host_timestamp();
+        if self.enabled && now > self.timestamp {
+            self.second_of_day = (self.second_of_day + now - self.timestamp) % SECONDS_PER_DAY;
+        }
+        self.timestamp = now;
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    pub fn minutes_bcd(&self) -> u8 {
+        to_bcd((self.second_of_day / 60 % 60) as u8)
+    }
+
+    pub fn hours_bcd(&self) -> u8 {
+        to_bcd((self.second_of_day / 3600) as u8)
+    }
+
+    /// Setting the minutes also resets the seconds.
+    pub fn set_minutes_bcd(&mut self, value: u8) {
+        let hours = self.second_of_day / 3600;
+        let minutes = from_bcd(value).min(59) as u64;
+        self.second_of_day = hours * 3600 + minutes * 60;
+        self.clock_accumulator = 0;
+    }
+
+    pub fn set_hours_bcd(&mut self, value: u8) {
+        let hours = from_bcd(value).min(23) as u64;
+        self.second_of_day = hours * 3600 + self.second_of_day % 3600;
+    }
+
+    /// Serializes the clock into a 16 byte save file footer: second of the day and the enabled flag as 32 bit little endian values,
+    /// followed by a 64 bit unix timestamp.
+    pub fn to_save_footer(&self) -> Vec<u8> {
+        let mut footer = Vec::with_capacity(SAVE_FOOTER_LENGTH);
+        footer.extend_from_slice(&(self.second_of_day as u32).to_le_bytes());
+        footer.extend_from_slice(&(self.enabled as u32).to_le_bytes());
+        footer.extend_from_slice(&self.timestamp.to_le_bytes());
+        footer
+    }
+
+    pub fn load_save_footer(&mut self, footer: &[u8]) {
+        if footer.len() < SAVE_FOOTER_LENGTH {
+            return;
+        }
+
+        let read_u32 = |offset: usize| {
+            u32::from_le_bytes([
+                footer[offset],
+                footer[offset + 1],
+                footer[offset + 2],
+                footer[offset + 3],
+            ])
+        };
+        self.second_of_day = read_u32(0) as u64 % SECONDS_PER_DAY;
+        self.enabled = read_u32(4) > 0;
+        self.clock_accumulator = 0;
+
+        let mut timestamp = [0u8; 8];
+        timestamp.copy_from_slice(&footer[8..16]);
+        self.timestamp = u64::from_le_bytes(timestamp);
+    }
+}
+
+fn to_bcd(value: u8) -> u8 {
+    ((value / 10) << 4) | (value % 10)
+}
+
+fn from_bcd(value: u8) -> u8 {
+    (value >> 4) * 10 + (value & 0xF)
+}
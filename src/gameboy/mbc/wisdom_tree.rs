@@ -0,0 +1,94 @@
+use std::fmt::{Display, Formatter};
+
+use crate::gameboy::mbc::cartridge_header::CartridgeHeader;
+use crate::gameboy::mbc::mbc::Mbc;
+use crate::gameboy::memory::memory::Memory;
+
+const ROM_BANK_SIZE: usize = 0x8000;
+
+/// Unlicensed mapper of Wisdom Tree's games
+///
+/// Any write to 0x0000-0x3FFF switches the whole 32 KiB area at 0x0000-0x7FFF. The bank number is taken from the lower bits of the
+/// address, the written value is ignored.
+pub struct WisdomTree {
+    rom_banks: Vec<[u8; ROM_BANK_SIZE]>,
+    rom_bank: usize,
+    header: CartridgeHeader,
+}
+
+impl WisdomTree {
+    pub fn new(cartridge_data: &[u8], header: CartridgeHeader) -> Self {
+        let bank_count = (cartridge_data.len() / ROM_BANK_SIZE).clamp(1, 64);
+        let mut result = Self {
+            rom_banks: vec![[0; ROM_BANK_SIZE]; bank_count],
+            rom_bank: 0,
+            header,
+        };
+
+        // COPY ROM BANKS
+        for (bank_index, bank_data) in cartridge_data
+            .chunks(ROM_BANK_SIZE)
+            .take(bank_count)
+            .enumerate()
+        {
+            result.rom_banks[bank_index][0..bank_data.len()].copy_from_slice(bank_data);
+        }
+
+        result
+    }
+
+    /// Wisdom Tree cartridges claim to be plain 32 KiB ROMs, but are larger and carry the company name.
+    pub fn detect(data: &[u8], header: &CartridgeHeader) -> bool {
+        let names: [&[u8]; 2] = [b"WISDOM TREE", b"WISDOM\x00TREE"];
+        matches!(header.cartridge_type, 0x00 | 0xC0)
+            && data.len() > ROM_BANK_SIZE
+            && names
+                .iter()
+                .any(|name| data.windows(name.len()).any(|window| window == *name))
+    }
+}
+
+impl Memory for WisdomTree {
+    fn accepts_address(&self, address: u16) -> bool {
+        address < 0x8000 || (0xA000..0xC000).contains(&address)
+    }
+
+    fn read_byte(&self, address: u16) -> u8 {
+        if address < 0x8000 {
+            return self.rom_banks[self.rom_bank][address as usize];
+        }
+
+        // THERE IS NO EXTERNAL RAM
+        0xFF
+    }
+
+    fn write_byte(&mut self, address: u16, _value: u8) {
+        if address < 0x4000 {
+            // SELECT ROM BANK NUMBER
+            self.rom_bank = (address & 0x3F) as usize % self.rom_banks.len();
+        }
+    }
+}
+
+impl Display for WisdomTree {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "WisdomTree running game {}", self.get_game_name())
+    }
+}
+
+impl Mbc for WisdomTree {
+    fn get_header(&self) -> &CartridgeHeader {
+        &self.header
+    }
+
+    /// Counts in 16 KiB banks like the other mappers, so each 32 KiB bank is made of two of them.
+    fn get_rom_bank(&self, address: u16) -> usize {
+        self.rom_bank * 2 + (address >= 0x4000) as usize
+    }
+
+    fn export_battery_ram(&self) -> Vec<u8> {
+        Vec::new()
+    }
+
+    fn import_battery_ram(&mut self, _data: &[u8]) {}
+}
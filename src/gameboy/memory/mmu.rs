@@ -3,35 +3,69 @@ use std::fmt::{Display, Formatter};
 use std::rc::Rc;
 
 use crate::gameboy::audio::apu::Apu;
+use crate::gameboy::cheat::cheat_engine::CheatEngine;
+use crate::gameboy::cpu::bus::Bus;
+use crate::gameboy::cpu::interrupt::Interrupt;
 use crate::gameboy::joypad::Joypad;
+use crate::gameboy::mbc::camera_image_source::CameraImageSource;
+use crate::gameboy::mbc::infrared::InfraredPeer;
 use crate::gameboy::mbc::mbc::Mbc;
+use crate::gameboy::memory::boot_rom::BootRom;
 use crate::gameboy::memory::memory;
 use crate::gameboy::memory::memory::Memory;
+use crate::gameboy::model::Model;
 use crate::gameboy::ppu::Ppu;
 use crate::gameboy::timer::Timer;
 
+/// OAM DMA takes 160 M-cycles after a setup cycle, one byte per M-cycle
+const DMA_LENGTH: u16 = 0xA0;
+
+struct DmaTransfer {
+    source: u16,
+    offset: u16,
+    setup: bool,
+    /// The byte on the bus that the transfer reads from
+    last_byte: u8,
+}
+
 pub struct Mmu {
+    boot_rom: BootRom,
     mbc: Box<dyn Mbc>,
     timer: Timer,
     unit_lut: Vec<Box<dyn Memory>>,
     dma: u8,
+    dma_transfer: Option<DmaTransfer>,
+    ie_register: u8,
+    key1: u8,
+    cgb_mode: bool,
+    double_speed_phase: bool,
+    vsync: bool,
     pub ppu: Ppu,
     pub apu: Apu,
     pub joypad: Joypad,
+    pub cheats: CheatEngine,
     if_register: Rc<RefCell<u8>>,
 }
 
 impl Mmu {
-    pub fn new(mbc: Box<dyn Mbc>) -> Self {
+    pub fn new(mbc: Box<dyn Mbc>, boot_rom: BootRom) -> Self {
         let if_reg = Rc::new(RefCell::new(0));
         Self {
+            cgb_mode: boot_rom.is_cgb(),
+            boot_rom,
             mbc,
             timer: Timer::new(Rc::clone(&if_reg)),
             unit_lut: Vec::new(),
             dma: 0,
+            dma_transfer: None,
+            ie_register: 0,
+            key1: 0,
+            double_speed_phase: false,
+            vsync: false,
             ppu: Ppu::new(Rc::clone(&if_reg)),
             apu: Apu::new(),
             joypad: Joypad::new(Rc::clone(&if_reg)),
+            cheats: CheatEngine::new(),
             if_register: if_reg,
         }
     }
@@ -52,19 +86,175 @@ impl Mmu {
             .find(|unit| unit.accepts_address(address))
     }
 
-    pub fn step(&mut self) -> bool {
+    /// Advances the system by one M-cycle of the CPU. In double speed mode the PPU, the APU and the cartridge only step every other
+    /// M-cycle.
+    pub fn step(&mut self) {
+        // THE TIMER AND OAM DMA RUN AT CPU SPEED
         self.timer.step();
-        let vsync = self.ppu.step();
+        self.step_dma();
+        if self.is_double_speed() {
+            self.double_speed_phase = !self.double_speed_phase;
+            if self.double_speed_phase {
+                return;
+            }
+        }
+
+        if self.ppu.step() {
+            self.vsync = true;
+        }
         self.apu.step();
-        vsync
+        self.mbc.step();
     }
 
-    fn dma_transfer(&mut self) {
-        let source_address = (self.dma as u16) << 8;
-        for i in 0..0xA0 {
-            self.write_byte(0xFE00 + i, self.read_byte(source_address + i));
+    /// Returns true if a frame was completed since the last call.
+    pub fn take_vsync(&mut self) -> bool {
+        std::mem::take(&mut self.vsync)
+    }
+
+    pub fn set_boot_rom(&mut self, boot_rom: BootRom) {
+        self.cgb_mode = boot_rom.is_cgb();
+        self.boot_rom = boot_rom;
+    }
+
+    /// Unmaps the boot ROM and sets the IO registers and the divider to the values the boot ROM of the given model leaves behind.
+    pub fn skip_boot(&mut self, model: Model) {
+        self.boot_rom = BootRom::none();
+        self.cgb_mode = model == Model::Cgb;
+
+        // THE EXACT DIVIDER VALUE IS ONLY DOCUMENTED FOR THE DMG AND MGB, THE OTHER VALUES ARE APPROXIMATIONS
+        self.timer.set_divider(match model {
+            Model::Dmg | Model::Mgb | Model::Sgb => 0xABCC,
+            Model::Cgb => 0x1EA0,
+        });
+
+        // THE APU HAS TO BE POWERED ON BEFORE ITS REGISTERS ACCEPT WRITES. THE TRIGGER BITS ARE LEFT OUT SINCE THE BOOT SOUND HAS
+        // ALREADY FADED OUT.
+        for (address, value) in [
+            (memory::TIMA, 0x00),
+            (memory::TMA, 0x00),
+            (memory::TAC, 0xF8),
+            (memory::NR52, 0xF1),
+            (memory::NR10, 0x80),
+            (memory::NR11, 0xBF),
+            (memory::NR12, 0xF3),
+            (memory::NR13, 0xFF),
+            (memory::NR14, 0x3F),
+            (memory::NR21, 0x3F),
+            (memory::NR22, 0x00),
+            (memory::NR23, 0xFF),
+            (memory::NR24, 0x3F),
+            (memory::NR30, 0x7F),
+            (memory::NR31, 0xFF),
+            (memory::NR32, 0x9F),
+            (memory::NR33, 0xFF),
+            (memory::NR34, 0x3F),
+            (memory::NR41, 0xFF),
+            (memory::NR42, 0x00),
+            (memory::NR43, 0x00),
+            (memory::NR44, 0x3F),
+            (memory::NR50, 0x77),
+            (memory::NR51, 0xF3),
+            (memory::LCDC, 0x91),
+            (memory::LCD_STAT, 0x85),
+            (memory::SCROLL_Y, 0x00),
+            (memory::SCROLL_X, 0x00),
+            (memory::LCD_LYC, 0x00),
+            (memory::BGP, 0xFC),
+            (memory::OBP0, 0xFF),
+            (memory::OBP1, 0xFF),
+            (memory::WY, 0x00),
+            (memory::WX, 0x00),
+            (memory::IF, 0xE1),
+            (memory::IE, 0x00),
+        ] {
+            self.write_byte(address, value);
         }
+        self.dma = 0xFF;
+        self.mbc.skip_boot();
+    }
+
+    pub fn get_mbc(&self) -> &dyn Mbc {
+        self.mbc.as_ref()
+    }
+
+    pub fn is_rumble_active(&self) -> bool {
+        self.mbc.is_rumble_active()
     }
+
+    pub fn take_speaker_tone(&mut self) -> Option<u8> {
+        self.mbc.take_speaker_tone()
+    }
+
+    pub fn set_tilt(&mut self, x: f32, y: f32) {
+        self.mbc.set_tilt(x, y);
+    }
+
+    pub fn connect_infrared(&mut self, peer: Box<dyn InfraredPeer>) {
+        self.mbc.connect_infrared(peer);
+    }
+
+    pub fn set_camera_image_source(&mut self, source: Box<dyn CameraImageSource>) {
+        self.mbc.set_camera_image_source(source);
+    }
+
+    /// Does the GameShark writes, has to be called once per frame during VBlank.
+    pub fn apply_cheat_writes(&mut self) {
+        let writes = self.cheats.ram_writes().to_vec();
+        for (address, value) in writes {
+            self.write_byte(address, value);
+        }
+    }
+
+    /// Returns true while a CGB runs in double speed mode, which is reflected by bit 7 of KEY1.
+    pub fn is_double_speed(&self) -> bool {
+        self.key1 & 0b1000_0000 > 0
+    }
+
+    fn start_dma(&mut self) {
+        // SOURCES ABOVE 0xDFFF READ FROM ECHO RAM
+        let mut source = (self.dma as u16) << 8;
+        if source >= 0xE000 {
+            source -= 0x2000;
+        }
+        self.dma_transfer = Some(DmaTransfer {
+            source,
+            offset: 0,
+            setup: true,
+            last_byte: 0xFF,
+        });
+    }
+
+    fn step_dma(&mut self) {
+        let Some(transfer) = self.dma_transfer.as_mut() else {
+            return;
+        };
+        if transfer.setup {
+            transfer.setup = false;
+            return;
+        }
+
+        let (source, offset) = (transfer.source, transfer.offset);
+        let value = self.read_byte(source + offset);
+        self.write_byte(0xFE00 + offset, value);
+
+        let transfer = self.dma_transfer.as_mut().unwrap();
+        transfer.last_byte = value;
+        transfer.offset += 1;
+        if transfer.offset == DMA_LENGTH {
+            self.dma_transfer = None;
+        }
+    }
+}
+
+fn is_oam(address: u16) -> bool {
+    (0xFE00..0xFEA0).contains(&address)
+}
+
+/// The DMG has the VRAM on its own bus, while ROM, cartridge RAM and WRAM share the external bus. HRAM and the IO registers are not
+/// affected by transfers.
+fn is_same_bus(address: u16, source: u16) -> bool {
+    let is_vram = |address: u16| (0x8000..0xA000).contains(&address);
+    address < 0xFE00 && is_vram(address) == is_vram(source)
 }
 
 impl Memory for Mmu {
@@ -79,11 +269,25 @@ impl Memory for Mmu {
         if address == memory::IF {
             return *self.if_register.borrow();
         }
+        if address == memory::IE {
+            return self.ie_register;
+        }
+        if address == memory::KEY1 {
+            // KEY1 ONLY EXISTS ON THE CGB
+            if !self.cgb_mode {
+                return 0xFF;
+            }
+            return 0b0111_1110 | self.key1;
+        }
+        if self.boot_rom.accepts_address(address) {
+            return self.boot_rom.read_byte(address);
+        }
         if self.ppu.accepts_address(address) {
             return self.ppu.read_byte(address);
         }
         if self.mbc.accepts_address(address) {
-            return self.mbc.read_byte(address);
+            // GAME GENIE CODES PATCH THE VALUES READ FROM THE CARTRIDGE
+            return self.cheats.patch_read(address, self.mbc.read_byte(address));
         }
         if self.timer.accepts_address(address) {
             return self.timer.read_byte(address);
@@ -103,13 +307,27 @@ impl Memory for Mmu {
     fn write_byte(&mut self, address: u16, value: u8) {
         if address == memory::DMA {
             self.dma = value;
-            self.dma_transfer();
+            self.start_dma();
             return;
         }
         if address == memory::IF {
             *(*self.if_register).borrow_mut() = value;
             return;
         }
+        if address == memory::IE {
+            self.ie_register = value;
+            return;
+        }
+        if address == memory::KEY1 {
+            if self.cgb_mode {
+                self.key1 = (self.key1 & 0b1000_0000) | (value & 0b1);
+            }
+            return;
+        }
+        if self.boot_rom.accepts_address(address) {
+            self.boot_rom.write_byte(address, value);
+            return;
+        }
         if self.ppu.accepts_address(address) {
             self.ppu.write_byte(address, value);
             return;
@@ -137,6 +355,79 @@ impl Memory for Mmu {
     }
 }
 
+impl Bus for Mmu {
+    fn tick(&mut self) {
+        self.step();
+    }
+
+    /// While OAM DMA runs, OAM reads return 0xFF and reads from the bus the transfer uses return the byte being transferred.
+    fn read(&mut self, address: u16) -> u8 {
+        if let Some(transfer) = self.dma_transfer.as_ref() {
+            if !transfer.setup {
+                if is_oam(address) {
+                    return 0xFF;
+                }
+                if is_same_bus(address, transfer.source) {
+                    return transfer.last_byte;
+                }
+            }
+        }
+
+        // SOME MAPPERS COUNT THE CPU'S READS, READS FOR TRACING AND DEBUGGING DON'T REACH THEM
+        if !self.boot_rom.accepts_address(address) && self.mbc.accepts_address(address) {
+            self.mbc.on_cpu_read(address);
+        }
+        self.read_byte(address)
+    }
+
+    /// While OAM DMA runs, writes to OAM and to the bus the transfer uses are lost.
+    fn write(&mut self, address: u16, value: u8) {
+        if let Some(transfer) = self.dma_transfer.as_ref() {
+            if !transfer.setup && (is_oam(address) || is_same_bus(address, transfer.source)) {
+                return;
+            }
+        }
+        self.write_byte(address, value);
+    }
+
+    fn peek(&self, address: u16) -> u8 {
+        self.read_byte(address)
+    }
+
+    fn pending_interrupts(&self) -> u8 {
+        self.ie_register & *self.if_register.borrow() & 0b1_1111
+    }
+
+    fn acknowledge_interrupt(&mut self, interrupt: Interrupt) {
+        self.set_bit(memory::IF, interrupt.bit_number(), false);
+    }
+
+    fn get_rom_bank(&self, address: u16) -> usize {
+        self.mbc.get_rom_bank(address)
+    }
+
+    fn is_joypad_line_low(&self) -> bool {
+        self.joypad.is_any_line_low()
+    }
+
+    fn reset_divider(&mut self) {
+        self.timer.set_divider(0);
+    }
+
+    /// Bit 0 of KEY1 arms the speed switch.
+    fn is_speed_switch_armed(&self) -> bool {
+        self.key1 & 0b1 > 0
+    }
+
+    fn switch_speed(&mut self) {
+        self.key1 = (self.key1 ^ 0b1000_0000) & 0b1000_0000;
+    }
+
+    fn clear_screen(&mut self) {
+        self.ppu.clear_screen();
+    }
+}
+
 impl Display for Mmu {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "Mmu")
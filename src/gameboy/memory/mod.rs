@@ -1,4 +1,4 @@
-pub mod interrupt_registers;
+pub mod boot_rom;
 pub mod memory;
 pub mod mmu;
 pub mod random_access_memory;
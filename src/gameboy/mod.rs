@@ -1,9 +1,11 @@
 pub mod audio;
+pub mod cheat;
 pub mod cpu;
 pub mod gameboy;
 mod joypad;
 pub mod mbc;
 pub mod memory;
+pub mod model;
 pub mod ppu;
 pub mod timer;
 pub mod util;
@@ -0,0 +1,26 @@
+use std::fmt::{Display, Formatter};
+
+/// The Game Boy hardware revision that is emulated. The model decides the register values the boot ROM leaves behind.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Model {
+    /// Original Game Boy
+    Dmg,
+    /// Game Boy Pocket
+    Mgb,
+    /// Super Game Boy
+    Sgb,
+    /// Game Boy Color
+    Cgb,
+}
+
+impl Display for Model {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Model::Dmg => "DMG",
+            Model::Mgb => "MGB",
+            Model::Sgb => "SGB",
+            Model::Cgb => "CGB",
+        };
+        write!(f, "{}", name)
+    }
+}
@@ -373,6 +373,13 @@ impl Ppu {
         (byte1 as u16) | (byte2 as u16) << 1
     }
 
+    /// Shows a blank screen, like the LCD while the system is in STOP mode.
+    pub fn clear_screen(&mut self) {
+        for column in self.front_buffer.iter_mut() {
+            column.fill(WHITE);
+        }
+    }
+
     fn oam_search(&mut self) {
         self.cycle_accumulator -= 80;
         self.set_state(PpuMode::PixelTransfer);
@@ -7,70 +7,74 @@ use crate::gameboy::memory::memory;
 use crate::gameboy::memory::memory::Memory;
 use crate::gameboy::util::bit_util::set_bit;
 
+/// DIV and TIMA are both driven by one 16 bit counter. DIV exposes its upper 8 bits, while TIMA increments on the falling edge of the
+/// counter bit selected by TAC.
 pub struct Timer {
-    div: u8,
+    counter: u16,
     tima: u8,
     tma: u8,
     tac: u8,
-    accumulator: usize,
-    divider_accumulator: usize,
+    reload_pending: bool,
     if_register: Rc<RefCell<u8>>,
 }
 
-const CLOCKS_PER_DIVIDER_INC: usize = 256;
-
 impl Timer {
     pub fn new(if_register: Rc<RefCell<u8>>) -> Self {
         Self {
-            div: 0x18,
+            counter: 0x1800,
             tima: 0x00,
             tma: 0x00,
             tac: 0xF8,
-            accumulator: 0,
-            divider_accumulator: 0,
+            reload_pending: false,
             if_register,
         }
     }
 
-    pub fn step(&mut self) {
-        self.step_divider();
-        self.step_timer();
+    /// Sets the full 16 bit divider counter, DIV exposes its upper 8 bits.
+    pub fn set_divider(&mut self, counter: u16) {
+        let old_bit = self.timer_bit();
+        self.counter = counter;
+        self.detect_falling_edge(old_bit);
     }
 
-    fn step_divider(&mut self) {
-        self.divider_accumulator += 4;
-        if self.divider_accumulator >= CLOCKS_PER_DIVIDER_INC {
-            self.divider_accumulator -= CLOCKS_PER_DIVIDER_INC;
-            self.div = self.div.wrapping_add(1);
+    pub fn step(&mut self) {
+        // TIMA READS 0 FOR ONE M-CYCLE AFTER AN OVERFLOW BEFORE TMA IS LOADED AND THE INTERRUPT IS REQUESTED
+        if self.reload_pending {
+            self.reload_pending = false;
+            self.tima = self.tma;
+            set_bit!(self.if_register.borrow_mut(), Interrupt::Timer.bit_number());
         }
+
+        let old_bit = self.timer_bit();
+        self.counter = self.counter.wrapping_add(4);
+        self.detect_falling_edge(old_bit);
     }
 
-    fn step_timer(&mut self) {
-        if (self.tac & 0b100) > 0 {
-            self.accumulator += 4;
-            let required_clocks = self.required_clocks();
-            if self.accumulator >= required_clocks {
-                self.accumulator -= required_clocks;
-                let timer = self.tima;
-                match timer.checked_add(1) {
-                    None => {
-                        self.tima = self.tma;
-                        set_bit!(self.if_register.borrow_mut(), Interrupt::Timer.bit_number());
-                    }
-                    Some(result) => {
-                        self.tima = result;
-                    }
-                }
-            }
-        }
+    /// Returns the counter bit selected by TAC, which is always low while the timer is disabled.
+    fn timer_bit(&self) -> bool {
+        let mask = match self.tac & 0b11 {
+            0b01 => 1 << 3,
+            0b10 => 1 << 5,
+            0b11 => 1 << 7,
+            _ => 1 << 9,
+        };
+        (self.tac & 0b100) > 0 && (self.counter & mask) > 0
     }
 
-    fn required_clocks(&self) -> usize {
-        match self.tac {
-            0b01 => 16,
-            0b10 => 64,
-            0b11 => 256,
-            _ => 1024,
+    /// Resetting DIV or changing TAC can also cause a falling edge, which increments TIMA as well.
+    fn detect_falling_edge(&mut self, old_bit: bool) {
+        if !old_bit || self.timer_bit() {
+            return;
+        }
+
+        match self.tima.checked_add(1) {
+            None => {
+                self.tima = 0;
+                self.reload_pending = true;
+            }
+            Some(result) => {
+                self.tima = result;
+            }
         }
     }
 }
@@ -85,7 +89,7 @@ impl Memory for Timer {
 
     fn read_byte(&self, address: u16) -> u8 {
         match address {
-            memory::DIV => self.div,
+            memory::DIV => (self.counter >> 8) as u8,
             memory::TIMA => self.tima,
             memory::TMA => self.tma,
             memory::TAC => self.tac,
@@ -98,16 +102,20 @@ impl Memory for Timer {
     fn write_byte(&mut self, address: u16, value: u8) {
         match address {
             memory::DIV => {
-                self.div = 0;
+                self.set_divider(0);
             }
             memory::TIMA => {
+                // A WRITE DURING THE M-CYCLE AFTER AN OVERFLOW CANCELS THE RELOAD
                 self.tima = value;
+                self.reload_pending = false;
             }
             memory::TMA => {
                 self.tma = value;
             }
             memory::TAC => {
+                let old_bit = self.timer_bit();
                 self.tac = value;
+                self.detect_falling_edge(old_bit);
             }
             _ => {
                 panic!("Invalid address: {}", address)
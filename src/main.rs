@@ -1,5 +1,6 @@
 #![windows_subsystem = "windows"]
 
+use std::io;
 use std::time::{Duration, Instant};
 
 use pixels::{PixelsBuilder, SurfaceTexture};
@@ -7,11 +8,17 @@ use sdl2::audio::{AudioQueue, AudioSpecDesired};
 use sdl2::event::{Event, WindowEvent};
 use sdl2::keyboard::Scancode;
 
+use crate::gameboy::cheat::memory_search::{SearchFilter, ValueSize, Watch};
+use crate::gameboy::cpu::cpu_event::CpuEvent;
+use crate::gameboy::cpu::disassembler::disassemble;
 use crate::gameboy::gameboy::Gameboy;
 use crate::gameboy::util::joypad_key::JoypadKey;
 
 mod gameboy;
 
+/// Search results are only watched or frozen once the search has narrowed them down to this many addresses
+const MAX_WATCHES: usize = 8;
+
 fn main() {
     println!("Starting Anemulator2");
     println!("{}", std::env::current_dir().unwrap().display());
@@ -23,7 +30,7 @@ fn main() {
     let video_subsystem = sdl
         .video()
         .expect("failed to initialize SDL video subsystem");
-    let window = video_subsystem
+    let mut window = video_subsystem
         .window("Anemulator2", 480, 432)
         .position_centered()
         .resizable()
@@ -53,7 +60,15 @@ fn main() {
 
     let mut event_pump = sdl.event_pump().expect("failed to get the event_pump");
 
-    let mut gameboy = Gameboy::new(String::from("assets/Dr. Mario (World).gb"));
+    let mut gameboy = match Gameboy::try_new(String::from("assets/Dr. Mario (World).gb")) {
+        Ok(gameboy) => gameboy,
+        Err(error) => {
+            println!("{error}");
+            return;
+        }
+    };
+
+    let mut watches: Vec<Watch> = Vec::new();
 
     'main: loop {
         let start = Instant::now();
@@ -63,10 +78,13 @@ fn main() {
             match event {
                 Event::KeyDown {
                     scancode: Some(code),
+                    repeat,
                     ..
                 } => {
                     if let Some(key) = map_scancode_key(code) {
                         gameboy.mmu.joypad.on_joypad_state_change(key, true);
+                    } else if !repeat {
+                        handle_memory_search_key(&mut gameboy, &mut watches, code);
                     }
                 }
 
@@ -79,6 +97,14 @@ fn main() {
                     }
                 }
 
+                // TILT THE CARTRIDGE BY MOVING THE MOUSE AWAY FROM THE CENTER OF THE WINDOW
+                Event::MouseMotion { x, y, .. } => {
+                    let (width, height) = window.size();
+                    let tilt_x = x as f32 / width as f32 * 2.0 - 1.0;
+                    let tilt_y = y as f32 / height as f32 * 2.0 - 1.0;
+                    gameboy.set_tilt(tilt_x, tilt_y);
+                }
+
                 Event::Quit { .. } => {
                     break 'main;
                 }
@@ -104,6 +130,21 @@ fn main() {
             }
         }
 
+        // REPORT CPU EVENTS
+        for event in gameboy.take_cpu_events() {
+            println!("{event}");
+            match event {
+                CpuEvent::LockedUp { address, .. } => {
+                    println!("{}", disassemble(&gameboy.mmu, address));
+                }
+            }
+        }
+
+        // REPORT CARTRIDGE SPEAKER TONES
+        if let Some(tone) = gameboy.take_speaker_tone() {
+            println!("Cartridge speaker plays tone {tone}");
+        }
+
         // RENDER TO SCREEN
         let screen = pixels.get_frame_mut();
         let ppu_buffer = gameboy.mmu.ppu.front_buffer.as_ref();
@@ -121,12 +162,124 @@ fn main() {
         }
         pixels.render().expect("failed to render framebuffer");
 
+        // SHOW RUMBLE INDICATOR AND WATCHED VALUES
+        let mut title = String::from("Anemulator2");
+        if gameboy.is_rumble_active() {
+            title.push_str(" [RUMBLE]");
+        }
+        for watch in watches.iter() {
+            title.push_str(&format!(
+                " {:04X}={:X}",
+                watch.address,
+                watch.read(&gameboy.mmu)
+            ));
+        }
+        if window.title() != title {
+            window
+                .set_title(&title)
+                .expect("failed to update the window title");
+        }
+
         // PRINT FRAME TIME
         let duration = start.elapsed();
         println!("frame-time: {duration:?}");
     }
 }
 
+/// F1 and F2 start searching RAM for a byte or a word. F3 to F6 keep the addresses whose value stayed equal, changed, increased or
+/// decreased since the last step, the number keys those that hold that number. F7 shows the remaining addresses in the title bar and
+/// F8 freezes them at their current value. F9 switches logging the CPU's accesses to the shown addresses on and off.
+fn handle_memory_search_key(gameboy: &mut Gameboy, watches: &mut Vec<Watch>, code: Scancode) {
+    let filter = match code {
+        Scancode::F1 | Scancode::F2 => {
+            let size = if code == Scancode::F1 {
+                ValueSize::Byte
+            } else {
+                ValueSize::Word
+            };
+            gameboy.start_memory_search(size);
+            watches.clear();
+            println!("Started memory search for a {:?}", size);
+            return;
+        }
+        Scancode::F3 => SearchFilter::Equal,
+        Scancode::F4 => SearchFilter::Changed,
+        Scancode::F5 => SearchFilter::Increased,
+        Scancode::F6 => SearchFilter::Decreased,
+        Scancode::F7 | Scancode::F8 => {
+            let Some(search) = gameboy.get_memory_search() else {
+                return;
+            };
+            if search.is_empty() || search.len() > MAX_WATCHES {
+                println!(
+                    "{} addresses left, narrow the search down first",
+                    search.len()
+                );
+                return;
+            }
+            let results = search.results().to_vec();
+            for result in results {
+                if code == Scancode::F7 {
+                    watches.push(result.to_watch());
+                    continue;
+                }
+                let name = format!("Search {:04X}", result.address);
+                match result.to_cheat(&name, result.value) {
+                    Ok(cheat) => {
+                        println!("Adding cheat {}", cheat.name);
+                        gameboy.cheats().add(cheat);
+                    }
+                    Err(error) => println!("{}", error),
+                }
+            }
+            return;
+        }
+        Scancode::F9 => {
+            if gameboy.is_bus_traced() {
+                gameboy.set_bus_trace(None);
+            } else if let Some(start) = watches.iter().map(|watch| watch.address).min() {
+                let end = watches
+                    .iter()
+                    .map(|watch| watch.address + (watch.size == ValueSize::Word) as u16)
+                    .max()
+                    .unwrap_or(start);
+                gameboy.set_bus_trace(Some((start..=end, Box::new(io::stdout()))));
+            }
+            return;
+        }
+        _ => match map_scancode_digit(code) {
+            Some(digit) => SearchFilter::EqualTo(digit),
+            None => return,
+        },
+    };
+
+    let results = gameboy.filter_memory_search(filter);
+    println!(
+        "{} addresses left after filtering with {:?}",
+        results.len(),
+        filter
+    );
+    for result in results.iter().take(MAX_WATCHES) {
+        println!("{:04X}: {:X}", result.address, result.value);
+    }
+}
+
+fn map_scancode_digit(code: Scancode) -> Option<u16> {
+    match code {
+        Scancode::Num0 => Some(0),
+        Scancode::Num1 => Some(1),
+        Scancode::Num2 => Some(2),
+        Scancode::Num3 => Some(3),
+        Scancode::Num4 => Some(4),
+        Scancode::Num5 => Some(5),
+        Scancode::Num6 => Some(6),
+        Scancode::Num7 => Some(7),
+        Scancode::Num8 => Some(8),
+        Scancode::Num9 => Some(9),
+        _ => None,
+    }
+}
+
 fn map_scancode_key(code: Scancode) -> Option<JoypadKey> {
     match code {
         Scancode::Up => Some(JoypadKey::Up),